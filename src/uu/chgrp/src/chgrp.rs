@@ -40,12 +40,18 @@ fn parse_gid_and_uid(matches: &ArgMatches) -> UResult<GidUidOwnerFilter> {
         } else {
             match entries::grp2gid(group) {
                 Ok(g) => Some(g),
-                _ => {
-                    return Err(USimpleError::new(
-                        1,
-                        format!("invalid group: {}", group.quote()),
-                    ))
-                }
+                // It's possible that `group` contains a numeric group ID,
+                // in which case, we respect that, even if it's not the ID
+                // of any named group.
+                _ => match group.parse() {
+                    Ok(gid) => Some(gid),
+                    Err(_) => {
+                        return Err(USimpleError::new(
+                            1,
+                            format!("invalid group: {}", group.quote()),
+                        ))
+                    }
+                },
             }
         }
     };
@@ -145,6 +151,7 @@ pub fn uu_app() -> Command {
             Arg::new(options::traverse::TRAVERSE)
                 .short(options::traverse::TRAVERSE.chars().next().unwrap())
                 .help("if a command line argument is a symbolic link to a directory, traverse it")
+                .overrides_with_all([options::traverse::EVERY, options::traverse::NO_TRAVERSE])
                 .action(ArgAction::SetTrue),
         )
         .arg(
@@ -158,6 +165,7 @@ pub fn uu_app() -> Command {
             Arg::new(options::traverse::EVERY)
                 .short(options::traverse::EVERY.chars().next().unwrap())
                 .help("traverse every symbolic link to a directory encountered")
+                .overrides_with_all([options::traverse::TRAVERSE, options::traverse::NO_TRAVERSE])
                 .action(ArgAction::SetTrue),
         )
 }
@@ -32,6 +32,22 @@ mod options {
     pub const RECURSIVE: &str = "recursive";
     pub const MODE: &str = "MODE";
     pub const FILE: &str = "FILE";
+    pub const TRAVERSE_ARG_DIR_SYM_LINK: &str = "H";
+    pub const TRAVERSE_DIR_SYM_LINKS: &str = "L";
+    pub const NO_TRAVERSE_SYM_LINKS: &str = "P";
+}
+
+/// How `--recursive` should treat symbolic links to directories.
+///
+/// Mirrors the `-H`/`-L`/`-P` flags shared with `chcon`, `cp` and `du`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TraversalMode {
+    /// `-P` (default): never traverse a symbolic link.
+    DoNotTraverse,
+    /// `-H`: traverse a symbolic link only if it is a command line argument.
+    TraverseArgDirSymLink,
+    /// `-L`: traverse every symbolic link to a directory encountered.
+    TraverseAllDirSymLinks,
 }
 
 /// Extract negative modes (starting with '-') from the rest of the arguments.
@@ -99,6 +115,13 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let verbose = matches.get_flag(options::VERBOSE);
     let preserve_root = matches.get_flag(options::PRESERVE_ROOT);
     let recursive = matches.get_flag(options::RECURSIVE);
+    let traversal_mode = if matches.get_flag(options::TRAVERSE_DIR_SYM_LINKS) {
+        TraversalMode::TraverseAllDirSymLinks
+    } else if matches.get_flag(options::TRAVERSE_ARG_DIR_SYM_LINK) {
+        TraversalMode::TraverseArgDirSymLink
+    } else {
+        TraversalMode::DoNotTraverse
+    };
     let fmode = match matches.get_one::<String>(options::REFERENCE) {
         Some(fref) => match fs::metadata(fref) {
             Ok(meta) => Some(meta.mode() & 0o7777),
@@ -143,6 +166,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         verbose,
         preserve_root,
         recursive,
+        traversal_mode,
         fmode,
         cmode,
     };
@@ -199,6 +223,48 @@ pub fn uu_app() -> Command {
                 .help("change files and directories recursively")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(options::TRAVERSE_ARG_DIR_SYM_LINK)
+                .short('H')
+                .requires(options::RECURSIVE)
+                .overrides_with_all([
+                    options::TRAVERSE_DIR_SYM_LINKS,
+                    options::NO_TRAVERSE_SYM_LINKS,
+                ])
+                .help(
+                    "if a command line argument is a symbolic link to a directory, \
+                     traverse it. Only valid when -R is specified.",
+                )
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(options::TRAVERSE_DIR_SYM_LINKS)
+                .short('L')
+                .requires(options::RECURSIVE)
+                .overrides_with_all([
+                    options::TRAVERSE_ARG_DIR_SYM_LINK,
+                    options::NO_TRAVERSE_SYM_LINKS,
+                ])
+                .help(
+                    "traverse every symbolic link to a directory encountered. \
+                     Only valid when -R is specified.",
+                )
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(options::NO_TRAVERSE_SYM_LINKS)
+                .short('P')
+                .requires(options::RECURSIVE)
+                .overrides_with_all([
+                    options::TRAVERSE_ARG_DIR_SYM_LINK,
+                    options::TRAVERSE_DIR_SYM_LINKS,
+                ])
+                .help(
+                    "do not traverse any symbolic links (default). \
+                     Only valid when -R is specified.",
+                )
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(options::REFERENCE)
                 .long("reference")
@@ -224,6 +290,7 @@ struct Chmoder {
     verbose: bool,
     preserve_root: bool,
     recursive: bool,
+    traversal_mode: TraversalMode,
     fmode: Option<u32>,
     cmode: Option<String>,
 }
@@ -273,7 +340,7 @@ impl Chmoder {
                 ));
             }
             if self.recursive {
-                r = self.walk_dir(file);
+                r = self.walk_dir(file, true, &mut Vec::new());
             } else {
                 r = self.chmod_file(file).and(r);
             }
@@ -281,15 +348,54 @@ impl Chmoder {
         r
     }
 
-    fn walk_dir(&self, file_path: &Path) -> UResult<()> {
+    /// Whether `path`, a symbolic link, should be traversed as a directory
+    /// given `self.traversal_mode` and whether `path` was given directly on
+    /// the command line (as opposed to encountered while walking a tree).
+    fn should_traverse_symlink(&self, path: &Path, is_command_line_arg: bool) -> bool {
+        path.is_dir()
+            && match self.traversal_mode {
+                TraversalMode::DoNotTraverse => false,
+                TraversalMode::TraverseArgDirSymLink => is_command_line_arg,
+                TraversalMode::TraverseAllDirSymLinks => true,
+            }
+    }
+
+    fn walk_dir(
+        &self,
+        file_path: &Path,
+        is_command_line_arg: bool,
+        ancestors: &mut Vec<(u64, u64)>,
+    ) -> UResult<()> {
         let mut r = self.chmod_file(file_path);
-        if !file_path.is_symlink() && file_path.is_dir() {
+        let is_traversed_symlink = file_path.is_symlink()
+            && self.should_traverse_symlink(file_path, is_command_line_arg);
+        if is_traversed_symlink || (!file_path.is_symlink() && file_path.is_dir()) {
+            let dev_ino = is_traversed_symlink
+                .then(|| fs::metadata(file_path).ok())
+                .flatten()
+                .map(|meta| (meta.dev(), meta.ino()));
+            if let Some(id) = dev_ino {
+                if ancestors.contains(&id) {
+                    show!(USimpleError::new(
+                        1,
+                        format!(
+                            "{}: not traversing: possible symbolic link loop",
+                            file_path.quote()
+                        ),
+                    ));
+                    return r;
+                }
+                ancestors.push(id);
+            }
             for dir_entry in file_path.read_dir()? {
                 let path = dir_entry?.path();
-                if !path.is_symlink() {
-                    r = self.walk_dir(path.as_path());
+                if !path.is_symlink() || self.should_traverse_symlink(&path, false) {
+                    r = self.walk_dir(path.as_path(), false, ancestors);
                 }
             }
+            if dev_ino.is_some() {
+                ancestors.pop();
+            }
         }
         r
     }
@@ -19,8 +19,8 @@ use uucore::{
     error::{FromIo, UError, UResult, USimpleError},
     format_usage, help_about, help_section, help_usage, show,
     sum::{
-        div_ceil, Blake2b, Digest, DigestWriter, Md5, Sha1, Sha224, Sha256, Sha384, Sha512, Sm3,
-        BSD, CRC, SYSV,
+        div_ceil, Blake2b, Blake3, Digest, DigestWriter, Md5, Sha1, Sha224, Sha256, Sha384,
+        Sha3_256, Sha3_384, Sha3_512, Sha512, Sm3, BSD, CRC, SYSV,
     },
 };
 
@@ -38,7 +38,11 @@ const ALGORITHM_OPTIONS_SHA256: &str = "sha256";
 const ALGORITHM_OPTIONS_SHA384: &str = "sha384";
 const ALGORITHM_OPTIONS_SHA512: &str = "sha512";
 const ALGORITHM_OPTIONS_BLAKE2B: &str = "blake2b";
+const ALGORITHM_OPTIONS_BLAKE3: &str = "blake3";
 const ALGORITHM_OPTIONS_SM3: &str = "sm3";
+const ALGORITHM_OPTIONS_SHA3_256: &str = "sha3-256";
+const ALGORITHM_OPTIONS_SHA3_384: &str = "sha3-384";
+const ALGORITHM_OPTIONS_SHA3_512: &str = "sha3-512";
 
 #[derive(Debug)]
 enum CkSumError {
@@ -136,6 +140,26 @@ fn detect_algo(
             Box::new(Sm3::new()) as Box<dyn Digest>,
             512,
         ),
+        ALGORITHM_OPTIONS_BLAKE3 => (
+            ALGORITHM_OPTIONS_BLAKE3,
+            Box::new(Blake3::new()) as Box<dyn Digest>,
+            256,
+        ),
+        ALGORITHM_OPTIONS_SHA3_256 => (
+            ALGORITHM_OPTIONS_SHA3_256,
+            Box::new(Sha3_256::new()) as Box<dyn Digest>,
+            256,
+        ),
+        ALGORITHM_OPTIONS_SHA3_384 => (
+            ALGORITHM_OPTIONS_SHA3_384,
+            Box::new(Sha3_384::new()) as Box<dyn Digest>,
+            384,
+        ),
+        ALGORITHM_OPTIONS_SHA3_512 => (
+            ALGORITHM_OPTIONS_SHA3_512,
+            Box::new(Sha3_512::new()) as Box<dyn Digest>,
+            512,
+        ),
         _ => unreachable!("unknown algorithm: clap should have prevented this case"),
     }
 }
@@ -414,7 +438,11 @@ pub fn uu_app() -> Command {
                     ALGORITHM_OPTIONS_SHA256,
                     ALGORITHM_OPTIONS_SHA384,
                     ALGORITHM_OPTIONS_SHA512,
+                    ALGORITHM_OPTIONS_SHA3_256,
+                    ALGORITHM_OPTIONS_SHA3_384,
+                    ALGORITHM_OPTIONS_SHA3_512,
                     ALGORITHM_OPTIONS_BLAKE2B,
+                    ALGORITHM_OPTIONS_BLAKE3,
                     ALGORITHM_OPTIONS_SM3,
                 ]),
         )
@@ -97,6 +97,93 @@ where
     Ok(())
 }
 
+/// Copy `source` to `dest`, replicating any holes already present in `source`.
+///
+/// Unlike [`sparse_copy`], which turns any long run of zero bytes into a
+/// hole, this only recreates holes that `source` itself already has, found
+/// via `lseek(2)`'s `SEEK_HOLE`/`SEEK_DATA`. This is what `--sparse=auto`
+/// (the default) is supposed to do: don't balloon an already-sparse file,
+/// but don't go looking for zero blocks in a dense one either.
+///
+/// Returns `Err` (without having written anything useful) if the source
+/// filesystem doesn't support `SEEK_HOLE`, so the caller can fall back to a
+/// plain dense copy.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn sparse_copy_via_seek_hole<P>(source: P, dest: P) -> std::io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    use std::os::unix::prelude::MetadataExt;
+
+    let src_file = File::open(source)?;
+    let dst_file = File::create(dest)?;
+    let src_fd = src_file.as_raw_fd();
+    let dst_fd = dst_file.as_raw_fd();
+
+    let size: libc::off_t = src_file.metadata()?.size().try_into().unwrap();
+    if unsafe { libc::ftruncate(dst_fd, size) } < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    if size == 0 {
+        return Ok(());
+    }
+
+    let mut buf = vec![0u8; 64 * 1024];
+    let mut pos: libc::off_t = 0;
+    while pos < size {
+        let data_start = unsafe { libc::lseek(src_fd, pos, libc::SEEK_DATA) };
+        if data_start < 0 {
+            let err = std::io::Error::last_os_error();
+            return match err.raw_os_error() {
+                // No more data after `pos`: the remainder is a hole, and the
+                // destination is already the right size thanks to ftruncate.
+                Some(libc::ENXIO) => Ok(()),
+                // SEEK_DATA isn't supported on this filesystem.
+                _ => Err(err),
+            };
+        }
+        let hole_start = match unsafe { libc::lseek(src_fd, data_start, libc::SEEK_HOLE) } {
+            offset if offset < 0 => size,
+            offset => offset,
+        };
+
+        let mut offset = data_start;
+        while offset < hole_start {
+            let want = std::cmp::min(buf.len() as libc::off_t, hole_start - offset) as usize;
+            let n = unsafe {
+                libc::pread(src_fd, buf.as_mut_ptr() as *mut libc::c_void, want, offset)
+            };
+            if n <= 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            unsafe {
+                libc::pwrite(dst_fd, buf.as_ptr() as *const libc::c_void, n as usize, offset)
+            };
+            offset += n as libc::off_t;
+        }
+        pos = hole_start;
+    }
+    Ok(())
+}
+
+/// Copy `source` to `dest`, preserving existing holes when possible.
+///
+/// Tries [`sparse_copy_via_seek_hole`] first; if the filesystem doesn't
+/// support `SEEK_HOLE`/`SEEK_DATA`, falls back to a plain dense copy.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn sparse_copy_auto<P>(source: P, dest: P) -> std::io::Result<SparseDebug>
+where
+    P: AsRef<Path>,
+{
+    match sparse_copy_via_seek_hole(&source, &dest) {
+        Ok(()) => Ok(SparseDebug::SeekHole),
+        Err(_) => {
+            std::fs::copy(source, dest)?;
+            Ok(SparseDebug::Unsupported)
+        }
+    }
+}
+
 /// Copy the contents of the given source FIFO to the given file.
 fn copy_fifo_contents<P>(source: P, dest: P) -> std::io::Result<u64>
 where
@@ -159,7 +246,17 @@ pub(crate) fn copy_on_write(
             copy_debug.reflink = OffloadReflinkDebug::No;
             sparse_copy(source, dest)
         }
-        (ReflinkMode::Never, _) => {
+        (ReflinkMode::Never, SparseMode::Auto) => {
+            copy_debug.reflink = OffloadReflinkDebug::No;
+            match sparse_copy_auto(source, dest) {
+                Ok(sparse_detection) => {
+                    copy_debug.sparse_detection = sparse_detection;
+                    Ok(())
+                }
+                Err(e) => Err(e),
+            }
+        }
+        (ReflinkMode::Never, SparseMode::Never) => {
             copy_debug.sparse_detection = SparseDebug::No;
             copy_debug.reflink = OffloadReflinkDebug::No;
             std::fs::copy(source, dest).map(|_| ())
@@ -170,8 +267,34 @@ pub(crate) fn copy_on_write(
             copy_debug.reflink = OffloadReflinkDebug::Unsupported;
             sparse_copy(source, dest)
         }
-
-        (ReflinkMode::Auto, _) => {
+        (ReflinkMode::Auto, SparseMode::Auto) => {
+            copy_debug.reflink = OffloadReflinkDebug::Unsupported;
+            if source_is_fifo {
+                copy_debug.sparse_detection = SparseDebug::No;
+                copy_fifo_contents(source, dest).map(|_| ())
+            } else {
+                match clone(source, dest, CloneFallback::Error) {
+                    Ok(()) => {
+                        // A clone preserves the source's holes at the filesystem
+                        // level, so no separate sparse detection is needed.
+                        copy_debug.reflink = OffloadReflinkDebug::Yes;
+                        copy_debug.sparse_detection = SparseDebug::No;
+                        Ok(())
+                    }
+                    Err(_) => {
+                        copy_debug.reflink = OffloadReflinkDebug::No;
+                        match sparse_copy_auto(source, dest) {
+                            Ok(sparse_detection) => {
+                                copy_debug.sparse_detection = sparse_detection;
+                                Ok(())
+                            }
+                            Err(e) => Err(e),
+                        }
+                    }
+                }
+            }
+        }
+        (ReflinkMode::Auto, SparseMode::Never) => {
             copy_debug.sparse_detection = SparseDebug::No;
             copy_debug.reflink = OffloadReflinkDebug::Unsupported;
             if source_is_fifo {
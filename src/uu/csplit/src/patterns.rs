@@ -107,7 +107,7 @@ fn extract_patterns(args: &[String]) -> Result<Vec<Pattern>, CsplitError> {
     let mut patterns = Vec::with_capacity(args.len());
     let to_match_reg =
         Regex::new(r"^(/(?P<UPTO>.+)/|%(?P<SKIPTO>.+)%)(?P<OFFSET>[\+-]\d+)?$").unwrap();
-    let execute_ntimes_reg = Regex::new(r"^\{(?P<TIMES>\d+)|\*\}$").unwrap();
+    let execute_ntimes_reg = Regex::new(r"^\{(?:(?P<TIMES>\d+)|\*)\}$").unwrap();
     let mut iter = args.iter().peekable();
 
     while let Some(arg) = iter.next() {
@@ -188,6 +188,18 @@ mod tests {
         assert!(get_patterns(input.as_slice()).is_err());
     }
 
+    #[test]
+    fn malformed_repeat_quantifier_is_not_treated_as_quantifier() {
+        // "{5" and "*}" are not valid `{N}`/`{*}` quantifiers, so they must
+        // be parsed as their own (invalid) patterns rather than silently
+        // accepted as a repeat count for the preceding pattern.
+        let input: Vec<String> = vec!["/10$/".to_string(), "{5".to_string()];
+        assert!(get_patterns(input.as_slice()).is_err());
+
+        let input: Vec<String> = vec!["/10$/".to_string(), "*}".to_string()];
+        assert!(get_patterns(input.as_slice()).is_err());
+    }
+
     #[test]
     fn up_to_line_pattern() {
         let input: Vec<String> = vec!["24", "42", "{*}", "50", "{4}"]
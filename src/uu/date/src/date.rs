@@ -24,6 +24,8 @@ use windows_sys::Win32::{Foundation::SYSTEMTIME, System::SystemInformation::SetS
 
 use uucore::shortcut_value_parser::ShortcutValueParser;
 
+mod weekday;
+
 // Options
 const DATE: &str = "date";
 const HOURS: &str = "hours";
@@ -43,6 +45,7 @@ const OPT_RFC_EMAIL: &str = "rfc-email";
 const OPT_RFC_3339: &str = "rfc-3339";
 const OPT_SET: &str = "set";
 const OPT_REFERENCE: &str = "reference";
+const OPT_RESOLUTION: &str = "resolution";
 const OPT_UNIVERSAL: &str = "universal";
 const OPT_UNIVERSAL_2: &str = "utc";
 
@@ -140,6 +143,16 @@ impl<'a> From<&'a str> for Rfc3339Format {
 pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let matches = uu_app().try_get_matches_from(args)?;
 
+    if matches.get_flag(OPT_RESOLUTION) {
+        let resolution = get_clock_resolution()?;
+        println!(
+            "{}.{:09}",
+            resolution.num_seconds(),
+            resolution.subsec_nanos()
+        );
+        return Ok(());
+    }
+
     let format = if let Some(form) = matches.get_one::<String>(OPT_FORMAT) {
         if !form.starts_with('+') {
             return Err(USimpleError::new(
@@ -167,7 +180,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
 
     let date_source = if let Some(date) = matches.get_one::<String>(OPT_DATE) {
         let ref_time = Local::now();
-        if let Ok(new_time) = parse_datetime::parse_datetime_at_date(ref_time, date.as_str()) {
+        if let Some(new_time) = weekday::parse_human_date(ref_time, date.as_str()) {
             let duration = new_time.signed_duration_since(ref_time);
             DateSource::Human(duration)
         } else {
@@ -370,6 +383,12 @@ pub fn uu_app() -> Command {
                 .value_hint(clap::ValueHint::AnyPath)
                 .help("display the last modification time of FILE"),
         )
+        .arg(
+            Arg::new(OPT_RESOLUTION)
+                .long(OPT_RESOLUTION)
+                .help("output the available resolution of timestamps")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(OPT_SET)
                 .short('s')
@@ -417,6 +436,36 @@ fn parse_date<S: AsRef<str> + Clone>(
     parse_datetime::parse_datetime(s.as_ref()).map_err(|e| (s.as_ref().into(), e))
 }
 
+#[cfg(all(unix, not(target_os = "macos"), not(target_os = "redox")))]
+/// Query the resolution of `CLOCK_REALTIME` (unix).
+/// See here for more:
+/// `<https://doc.rust-lang.org/libc/i686-unknown-linux-gnu/libc/fn.clock_getres.html>`
+/// `<https://linux.die.net/man/3/clock_getres>`
+fn get_clock_resolution() -> UResult<TimeDelta> {
+    let mut timespec = timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+
+    let result = unsafe { libc::clock_getres(CLOCK_REALTIME, &mut timespec) };
+
+    if result == 0 {
+        Ok(TimeDelta::seconds(timespec.tv_sec)
+            + TimeDelta::nanoseconds(timespec.tv_nsec as i64))
+    } else {
+        Err(std::io::Error::last_os_error()
+            .map_err_context(|| "cannot get clock resolution".to_string()))
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "redox", windows))]
+/// These platforms don't have a `clock_getres` equivalent wired up here, so
+/// report the one-nanosecond resolution that modern glibc reports for
+/// `CLOCK_REALTIME` rather than fail outright.
+fn get_clock_resolution() -> UResult<TimeDelta> {
+    Ok(TimeDelta::nanoseconds(1))
+}
+
 #[cfg(not(any(unix, windows)))]
 fn set_system_datetime(_date: DateTime<Utc>) -> UResult<()> {
     unimplemented!("setting date not implemented (unsupported target)");
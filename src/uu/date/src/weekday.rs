@@ -0,0 +1,153 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Extra date-string parsing that the `parse_datetime` crate doesn't cover.
+//!
+//! `parse_datetime` has no notion of weekday names at all, and it rejects
+//! compound expressions that mix a relative unit with a further offset
+//! (e.g. `"last month + 3 hours"`). GNU `date` accepts both, so we handle
+//! them here as a thin layer in front of `parse_datetime::parse_datetime_at_date`:
+//! peel off a recognized prefix, resolve it, then recurse on what's left
+//! using the resolved moment as the new reference time.
+//!
+//! This does not aim for full parity with GNU's weekday grammar (forms like
+//! `"monday next week"` are out of scope); it covers the `[this|next|last]
+//! <weekday>` prefixes and `<expr> + <expr>` / `<expr> - <expr>` chaining.
+
+use chrono::{DateTime, Datelike, Days, Local, TimeZone, Weekday};
+
+/// Try to parse `s` as a human date relative to `ref_time`, using the
+/// weekday-name and compound-offset extensions described above.
+///
+/// Returns `None` if none of the extensions apply; callers should fall back
+/// to `parse_datetime::parse_datetime_at_date` (which this function also
+/// tries first, so a direct success there is returned immediately).
+pub fn parse_human_date(ref_time: DateTime<Local>, s: &str) -> Option<DateTime<chrono::FixedOffset>> {
+    if let Ok(dt) = parse_datetime::parse_datetime_at_date(ref_time, s) {
+        return Some(dt);
+    }
+
+    let s = s.trim();
+
+    if let Some(dt) = parse_weekday_prefixed(ref_time, s) {
+        return Some(dt);
+    }
+
+    parse_compound_offset(ref_time, s)
+}
+
+/// Weekday name, optionally preceded by `this`/`next`/`last`, optionally
+/// followed by more text to apply relative to the resolved day.
+fn parse_weekday_prefixed(
+    ref_time: DateTime<Local>,
+    s: &str,
+) -> Option<DateTime<chrono::FixedOffset>> {
+    let mut rest = s;
+    let mut modifier = None;
+    for (word, m) in [("next", Modifier::Next), ("last", Modifier::Last), ("this", Modifier::This)] {
+        if let Some(stripped) = strip_word_ci(rest, word) {
+            rest = stripped;
+            modifier = Some(m);
+            break;
+        }
+    }
+
+    let (weekday, rest) = take_weekday(rest)?;
+    let resolved = resolve_weekday(ref_time, weekday, modifier.unwrap_or(Modifier::This));
+
+    let rest = rest.trim();
+    if rest.is_empty() {
+        return Some(resolved.with_timezone(resolved.offset()));
+    }
+    parse_human_date(resolved.with_timezone(&Local), rest)
+}
+
+/// `<expr> + <expr>` or `<expr> - <expr>`, where each half parses on its
+/// own but the combination doesn't (`parse_datetime` only understands a
+/// leading sign directly on a relative value, not a standalone `+`/`-`
+/// token joining two expressions).
+fn parse_compound_offset(
+    ref_time: DateTime<Local>,
+    s: &str,
+) -> Option<DateTime<chrono::FixedOffset>> {
+    for sep in [" + ", " - "] {
+        if let Some(pos) = s.find(sep) {
+            let (head, tail) = (&s[..pos], &s[pos + sep.len()..]);
+            let sign = if sep == " + " { "" } else { "-" };
+            let base = parse_human_date(ref_time, head)?;
+            let offset = parse_human_date(base.with_timezone(&Local), &format!("{sign}{tail}"))?;
+            return Some(offset);
+        }
+    }
+    None
+}
+
+#[derive(Clone, Copy)]
+enum Modifier {
+    This,
+    Next,
+    Last,
+}
+
+fn resolve_weekday(
+    ref_time: DateTime<Local>,
+    weekday: Weekday,
+    modifier: Modifier,
+) -> DateTime<Local> {
+    let today = ref_time.date_naive();
+    let forward = (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    let backward = (7 - forward) % 7;
+
+    let target = match modifier {
+        Modifier::This => today + Days::new(forward as u64),
+        Modifier::Next => today + Days::new(if forward == 0 { 7 } else { forward as u64 }),
+        Modifier::Last => today - Days::new(if backward == 0 { 7 } else { backward as u64 }),
+    };
+
+    Local
+        .from_local_datetime(&target.and_hms_opt(0, 0, 0).unwrap())
+        .single()
+        .unwrap_or(ref_time)
+}
+
+/// If `s` starts with `word` (case-insensitively) followed by whitespace or
+/// end of string, return the remainder with that prefix (and the following
+/// whitespace) stripped.
+fn strip_word_ci<'a>(s: &'a str, word: &str) -> Option<&'a str> {
+    let s_trimmed = s.trim_start();
+    if s_trimmed.len() < word.len() || !s_trimmed[..word.len()].eq_ignore_ascii_case(word) {
+        return None;
+    }
+    let rest = &s_trimmed[word.len()..];
+    if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
+
+const WEEKDAYS: &[(&str, &str, Weekday)] = &[
+    ("sunday", "sun", Weekday::Sun),
+    ("monday", "mon", Weekday::Mon),
+    ("tuesday", "tue", Weekday::Tue),
+    ("wednesday", "wed", Weekday::Wed),
+    ("thursday", "thu", Weekday::Thu),
+    ("friday", "fri", Weekday::Fri),
+    ("saturday", "sat", Weekday::Sat),
+];
+
+/// If `s` starts with a (full or three-letter) weekday name, return it along
+/// with the remainder of the string.
+fn take_weekday(s: &str) -> Option<(Weekday, &str)> {
+    for (full, abbr, weekday) in WEEKDAYS {
+        if let Some(rest) = strip_word_ci(s, full) {
+            return Some((*weekday, rest));
+        }
+        if let Some(rest) = strip_word_ci(s, abbr) {
+            return Some((*weekday, rest));
+        }
+    }
+    None
+}
@@ -0,0 +1,63 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! A byte buffer whose backing memory starts at an address aligned to a
+//! given power-of-two boundary.
+//!
+//! This is needed for `O_DIRECT` I/O (`iflag=direct`, `oflag=direct`):
+//! `read(2)`/`write(2)` on a file descriptor opened with `O_DIRECT` require
+//! the buffer address passed to the syscall to be aligned to the
+//! filesystem's logical block size, which a plain `Vec<u8>` does not
+//! guarantee.
+
+use std::alloc::{alloc_zeroed, dealloc, Layout};
+use std::ptr::NonNull;
+
+/// The alignment used for `O_DIRECT` buffers.
+///
+/// This is a conservative choice: it is a multiple of every commonly used
+/// logical block size (512 bytes) and matches the page size on all Linux
+/// architectures `dd` is built for.
+pub(crate) const DIRECT_IO_ALIGN: usize = 4096;
+
+/// An owned, zeroed buffer of a fixed length, aligned to `align` bytes.
+pub(crate) struct AlignedBuffer {
+    ptr: NonNull<u8>,
+    layout: Layout,
+    len: usize,
+}
+
+impl AlignedBuffer {
+    /// Allocate a zeroed buffer of `len` bytes, aligned to `align` bytes.
+    pub(crate) fn new(len: usize, align: usize) -> Self {
+        let layout = Layout::from_size_align(len.max(1), align).unwrap();
+        // SAFETY: `layout` has a non-zero size.
+        let ptr = unsafe { alloc_zeroed(layout) };
+        let ptr = NonNull::new(ptr).unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+        Self { ptr, layout, len }
+    }
+
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        // SAFETY: `ptr` points to `layout.size()` bytes owned by `self`,
+        // and `len <= layout.size()`.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: see `as_slice`.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for AlignedBuffer {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` was allocated by `alloc_zeroed` with `layout` and
+        // has not been freed before.
+        unsafe { dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+// `AlignedBuffer` owns its memory exclusively, like a `Vec<u8>`.
+unsafe impl Send for AlignedBuffer {}
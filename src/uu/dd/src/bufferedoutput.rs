@@ -117,6 +117,8 @@ mod tests {
         let inner = Output {
             dst: Dest::Sink,
             settings: &settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
         };
         let mut output = BufferedOutput::new(inner);
         let wstat = output.write_blocks(&[]).unwrap();
@@ -135,6 +137,8 @@ mod tests {
         let inner = Output {
             dst: Dest::Sink,
             settings: &settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
         };
         let mut output = BufferedOutput::new(inner);
         let wstat = output.write_blocks(b"ab").unwrap();
@@ -153,6 +157,8 @@ mod tests {
         let inner = Output {
             dst: Dest::Sink,
             settings: &settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
         };
         let mut output = BufferedOutput::new(inner);
         let wstat = output.write_blocks(b"abcd").unwrap();
@@ -171,6 +177,8 @@ mod tests {
         let inner = Output {
             dst: Dest::Sink,
             settings: &settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
         };
         let mut output = BufferedOutput {
             inner,
@@ -192,6 +200,8 @@ mod tests {
         let inner = Output {
             dst: Dest::Sink,
             settings: &settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
         };
         let mut output = BufferedOutput {
             inner,
@@ -5,6 +5,7 @@
 
 // spell-checker:ignore fname, ftype, tname, fpath, specfile, testfile, unspec, ifile, ofile, outfile, fullblock, urand, fileio, atoe, atoibm, behaviour, bmax, bremain, cflags, creat, ctable, ctty, datastructures, doesnt, etoa, fileout, fname, gnudd, iconvflags, iseek, nocache, noctty, noerror, nofollow, nolinks, nonblock, oconvflags, oseek, outfile, parseargs, rlen, rmax, rremain, rsofar, rstat, sigusr, wlen, wstat seekable oconv canonicalized fadvise Fadvise FADV DONTNEED ESPIPE bufferedoutput, SETFL
 
+mod aligned_buffer;
 mod blocks;
 mod bufferedoutput;
 mod conversion_tables;
@@ -13,6 +14,8 @@ mod numbers;
 mod parseargs;
 mod progress;
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::aligned_buffer::{AlignedBuffer, DIRECT_IO_ALIGN};
 use crate::bufferedoutput::BufferedOutput;
 use blocks::conv_block_unblock_helper;
 use datastructures::*;
@@ -310,6 +313,15 @@ struct Input<'a> {
 
     /// Configuration settings for how to read the data.
     settings: &'a Settings,
+
+    /// A page-aligned scratch buffer used for `iflag=direct` reads.
+    ///
+    /// `O_DIRECT` requires the address passed to `read(2)` to be aligned to
+    /// the filesystem's logical block size, which a plain `Vec<u8>` does
+    /// not guarantee. Allocated lazily, on the first read, once `ibs` is
+    /// known to be stable for the rest of the run.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    direct_buf: Option<AlignedBuffer>,
 }
 
 impl<'a> Input<'a> {
@@ -348,7 +360,12 @@ impl<'a> Input<'a> {
         if settings.skip > 0 {
             src.skip(settings.skip)?;
         }
-        Ok(Self { src, settings })
+        Ok(Self {
+            src,
+            settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
+        })
     }
 
     /// Instantiate this struct with the named file as a source.
@@ -370,7 +387,12 @@ impl<'a> Input<'a> {
         if settings.skip > 0 {
             src.skip(settings.skip)?;
         }
-        Ok(Self { src, settings })
+        Ok(Self {
+            src,
+            settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
+        })
     }
 
     /// Instantiate this struct with the named pipe as a source.
@@ -384,7 +406,33 @@ impl<'a> Input<'a> {
         if settings.skip > 0 {
             src.skip(settings.skip)?;
         }
-        Ok(Self { src, settings })
+        Ok(Self {
+            src,
+            settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
+        })
+    }
+
+    /// Read into `buf`, routing through a page-aligned scratch buffer when
+    /// `iflag=direct` is in effect.
+    ///
+    /// `O_DIRECT` reads must land in an aligned buffer (see [`AlignedBuffer`]),
+    /// which a plain `Vec<u8>`-backed `buf` does not guarantee, so the actual
+    /// `read(2)` call goes through `direct_buf` and is then copied out.
+    fn src_read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if self.settings.iflags.direct {
+            let ibs = self.settings.ibs;
+            let scratch = self
+                .direct_buf
+                .get_or_insert_with(|| AlignedBuffer::new(ibs, DIRECT_IO_ALIGN));
+            let len = buf.len().min(scratch.as_slice().len());
+            let rlen = self.src.read(&mut scratch.as_mut_slice()[..len])?;
+            buf[..rlen].copy_from_slice(&scratch.as_slice()[..rlen]);
+            return Ok(rlen);
+        }
+        self.src.read(buf)
     }
 }
 
@@ -429,7 +477,7 @@ impl<'a> Read for Input<'a> {
         let mut base_idx = 0;
         let target_len = buf.len();
         loop {
-            match self.src.read(&mut buf[base_idx..]) {
+            match self.src_read(&mut buf[base_idx..]) {
                 Ok(0) => return Ok(base_idx),
                 Ok(rlen) if self.settings.iflags.fullblock => {
                     base_idx += rlen;
@@ -628,6 +676,24 @@ impl Dest {
         }
     }
 
+    /// Clear the `O_DIRECT` flag on the underlying file descriptor, if any.
+    ///
+    /// Used to fall back to buffered I/O for a final, partial `oflag=direct`
+    /// write whose length isn't a multiple of the logical block size.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn clear_direct(&self) -> io::Result<()> {
+        let fd = match self {
+            Self::File(f, _) => f.as_raw_fd(),
+            #[cfg(unix)]
+            Self::Fifo(f) => f.as_raw_fd(),
+            _ => return Ok(()),
+        };
+        let flags = nix::fcntl::fcntl(fd, nix::fcntl::FcntlArg::F_GETFL)?;
+        let flags = OFlag::from_bits_retain(flags) & !OFlag::O_DIRECT;
+        nix::fcntl::fcntl(fd, F_SETFL(flags))?;
+        Ok(())
+    }
+
     /// Truncate the underlying file to the current stream position, if possible.
     fn truncate(&mut self) -> io::Result<()> {
         match self {
@@ -716,6 +782,12 @@ struct Output<'a> {
 
     /// Configuration settings for how to read and write the data.
     settings: &'a Settings,
+
+    /// A page-aligned scratch buffer used for `oflag=direct` writes.
+    ///
+    /// See the analogous field on [`Input`] for why this is needed.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    direct_buf: Option<AlignedBuffer>,
 }
 
 impl<'a> Output<'a> {
@@ -724,7 +796,12 @@ impl<'a> Output<'a> {
         let mut dst = Dest::Stdout(io::stdout());
         dst.seek(settings.seek)
             .map_err_context(|| "write error".to_string())?;
-        Ok(Self { dst, settings })
+        Ok(Self {
+            dst,
+            settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
+        })
     }
 
     /// Instantiate this struct with the named file as a destination.
@@ -771,7 +848,12 @@ impl<'a> Output<'a> {
         let mut dst = Dest::File(dst, density);
         dst.seek(settings.seek)
             .map_err_context(|| "failed to seek in output file".to_string())?;
-        Ok(Self { dst, settings })
+        Ok(Self {
+            dst,
+            settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
+        })
     }
 
     /// Instantiate this struct with file descriptor as a destination.
@@ -806,7 +888,12 @@ impl<'a> Output<'a> {
         // indefinitely.
         if let Some(Num::Blocks(0) | Num::Bytes(0)) = settings.count {
             let dst = Dest::Sink;
-            return Ok(Self { dst, settings });
+            return Ok(Self {
+                dst,
+                settings,
+                #[cfg(any(target_os = "linux", target_os = "android"))]
+                direct_buf: None,
+            });
         }
         // At this point, we know there is at least one block to write
         // to the output, so we open the file for writing.
@@ -818,7 +905,12 @@ impl<'a> Output<'a> {
         #[cfg(any(target_os = "linux", target_os = "android"))]
         opts.custom_flags(make_linux_oflags(&settings.oflags).unwrap_or(0));
         let dst = Dest::Fifo(opts.open(filename)?);
-        Ok(Self { dst, settings })
+        Ok(Self {
+            dst,
+            settings,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            direct_buf: None,
+        })
     }
 
     /// Discard the system file cache for the given portion of the output.
@@ -844,6 +936,31 @@ impl<'a> Output<'a> {
         }
     }
 
+    /// Write `buf`, routing through a page-aligned scratch buffer when
+    /// `oflag=direct` is in effect.
+    ///
+    /// See [`Input::src_read`] for why this is needed. A final, partial
+    /// block can't be written through `O_DIRECT` (its length isn't a
+    /// multiple of the filesystem's logical block size), so for that case
+    /// `O_DIRECT` is cleared on the destination first, matching GNU `dd`.
+    fn dst_write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if self.settings.oflags.direct {
+            let obs = self.settings.obs;
+            if buf.len() < obs {
+                self.dst.clear_direct()?;
+                return self.dst.write(buf);
+            }
+            let scratch = self
+                .direct_buf
+                .get_or_insert_with(|| AlignedBuffer::new(obs, DIRECT_IO_ALIGN));
+            let len = buf.len().min(scratch.as_slice().len());
+            scratch.as_mut_slice()[..len].copy_from_slice(&buf[..len]);
+            return self.dst.write(&scratch.as_slice()[..len]);
+        }
+        self.dst.write(buf)
+    }
+
     /// writes a block of data. optionally retries when first try didn't complete
     ///
     /// this is needed by gnu-test: tests/dd/stats.s
@@ -854,7 +971,7 @@ impl<'a> Output<'a> {
         let full_len = chunk.len();
         let mut base_idx = 0;
         loop {
-            match self.dst.write(&chunk[base_idx..]) {
+            match self.dst_write(&chunk[base_idx..]) {
                 Ok(wlen) => {
                     base_idx += wlen;
                     // take iflags.fullblock as oflags shall not have this option
@@ -547,22 +547,14 @@ pub fn parse_bytes_with_opt_multiplier(s: &str) -> Result<u64, ParseError> {
 
     // Split on the 'x' characters. Each component will be parsed
     // individually, then multiplied together.
-    let parts: Vec<&str> = s.split('x').collect();
-    if parts.len() == 1 {
-        parse_bytes_no_x(s, parts[0])
-    } else {
-        let mut total: u64 = 1;
-        for part in parts {
-            if part == "0" {
-                show_zero_multiplier_warning();
-            }
-            let num = parse_bytes_no_x(s, part)?;
-            total = total
-                .checked_mul(num)
-                .ok_or_else(|| ParseError::InvalidNumber(s.to_string()))?;
+    let has_multiplier = s.contains('x');
+    let total = uucore::parse_size::parse_size_multiplier_chain(s, |part| {
+        if has_multiplier && part == "0" {
+            show_zero_multiplier_warning();
         }
-        Ok(total)
-    }
+        parse_bytes_no_x(s, part)
+    })?;
+    total.ok_or_else(|| ParseError::InvalidNumber(s.to_string()))
 }
 
 fn get_ctable(
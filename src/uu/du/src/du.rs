@@ -27,6 +27,7 @@ use std::thread;
 use std::time::{Duration, UNIX_EPOCH};
 use uucore::display::{print_verbatim, Quotable};
 use uucore::error::{set_exit_code, FromIo, UError, UResult, USimpleError};
+use uucore::json::JsonValue;
 use uucore::line_ending::LineEnding;
 use uucore::parse_glob;
 use uucore::parse_size::{parse_size_u64, ParseSizeError};
@@ -66,6 +67,7 @@ mod options {
     pub const EXCLUDE: &str = "exclude";
     pub const EXCLUDE_FROM: &str = "exclude-from";
     pub const FILES0_FROM: &str = "files0-from";
+    pub const JSON: &str = "json";
     pub const VERBOSE: &str = "verbose";
     pub const FILE: &str = "FILE";
 }
@@ -98,6 +100,7 @@ struct StatPrinter {
     time_format: String,
     line_ending: LineEnding,
     summarize: bool,
+    json: bool,
 }
 
 #[derive(PartialEq, Clone)]
@@ -297,6 +300,57 @@ fn read_block_size(s: Option<&str>) -> UResult<u64> {
     }
 }
 
+/// `stat()` every successfully read directory entry, spreading the (lstat)
+/// syscalls across a small thread pool.
+///
+/// This is the dominant per-entry cost in [`du`], especially on directories
+/// with many files or on network filesystems, so parallelizing just this
+/// step speeds up traversal while leaving dedup, exclude matching, output
+/// ordering and recursion exactly as they were: all sequential, in the
+/// caller, using the precomputed results.
+///
+/// Returns one entry per element of `entries`, `None` for entries that
+/// failed to be read in the first place (the caller handles those
+/// separately) and `Some` otherwise.
+fn stat_entries(
+    entries: &[std::io::Result<fs::DirEntry>],
+    options: &TraversalOptions,
+) -> Vec<Option<std::io::Result<Stat>>> {
+    let num_workers = thread::available_parallelism()
+        .map(std::num::NonZeroUsize::get)
+        .unwrap_or(1)
+        .min(entries.len().max(1));
+
+    let mut stats: Vec<Option<std::io::Result<Stat>>> = (0..entries.len()).map(|_| None).collect();
+
+    if num_workers <= 1 {
+        for (entry, slot) in entries.iter().zip(stats.iter_mut()) {
+            if let Ok(entry) = entry {
+                *slot = Some(Stat::new(&entry.path(), options));
+            }
+        }
+        return stats;
+    }
+
+    let chunk_size = entries.len().div_ceil(num_workers);
+    thread::scope(|scope| {
+        for (entry_chunk, stat_chunk) in entries
+            .chunks(chunk_size)
+            .zip(stats.chunks_mut(chunk_size))
+        {
+            scope.spawn(move || {
+                for (entry, slot) in entry_chunk.iter().zip(stat_chunk.iter_mut()) {
+                    if let Ok(entry) = entry {
+                        *slot = Some(Stat::new(&entry.path(), options));
+                    }
+                }
+            });
+        }
+    });
+
+    stats
+}
+
 // this takes `my_stat` to avoid having to stat files multiple times.
 #[allow(clippy::cognitive_complexity)]
 fn du(
@@ -317,10 +371,14 @@ fn du(
             }
         };
 
-        'file_loop: for f in read {
+        let entries: Vec<std::io::Result<fs::DirEntry>> = read.collect();
+        let stats = stat_entries(&entries, options);
+
+        'file_loop: for (f, stat) in entries.into_iter().zip(stats) {
             match f {
                 Ok(entry) => {
-                    match Stat::new(&entry.path(), options) {
+                    // SAFETY: `stat_entries` returns `Some` for every `Ok` entry.
+                    match stat.unwrap() {
                         Ok(this_stat) => {
                             // We have an exclude list
                             for pattern in &options.excludes {
@@ -536,7 +594,13 @@ impl StatPrinter {
         }
 
         if self.total {
-            print!("{}\ttotal", self.convert_size(grand_total));
+            if self.json {
+                let total =
+                    JsonValue::object([("path", JsonValue::Null), ("size", grand_total.into())]);
+                print!("{total}");
+            } else {
+                print!("{}\ttotal", self.convert_size(grand_total));
+            }
             print!("{}", self.line_ending);
         }
 
@@ -565,6 +629,27 @@ impl StatPrinter {
     }
 
     fn print_stat(&self, stat: &Stat, size: u64) -> UResult<()> {
+        if self.json {
+            let mut fields = vec![
+                (
+                    "path",
+                    JsonValue::from(stat.path.to_string_lossy().into_owned()),
+                ),
+                ("size", size.into()),
+            ];
+            if let Some(time) = self.time {
+                let secs = get_time_secs(time, stat)?;
+                let tm = DateTime::<Local>::from(UNIX_EPOCH + Duration::from_secs(secs));
+                fields.push((
+                    "time",
+                    JsonValue::from(tm.format(&self.time_format).to_string()),
+                ));
+            }
+            print!("{}", JsonValue::object(fields));
+            print!("{}", self.line_ending);
+            return Ok(());
+        }
+
         if let Some(time) = self.time {
             let secs = get_time_secs(time, stat)?;
             let tm = DateTime::<Local>::from(UNIX_EPOCH + Duration::from_secs(secs));
@@ -745,6 +830,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         time,
         time_format,
         line_ending: LineEnding::from_zero_flag(matches.get_flag(options::NULL)),
+        json: matches.get_flag(options::JSON),
     };
 
     if stat_printer.inodes
@@ -1010,6 +1096,13 @@ pub fn uu_app() -> Command {
                 .help("verbose mode (option not present in GNU/Coreutils)")
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(options::JSON)
+                .long("json")
+                .help("emit each entry as a JSON object, one per line, instead of columns \
+                          (option not present in GNU/Coreutils)")
+                .action(ArgAction::SetTrue)
+        )
         .arg(
             Arg::new(options::EXCLUDE)
                 .long(options::EXCLUDE)
@@ -1124,4 +1217,33 @@ mod test_du {
             assert!(matches!(read_block_size(it.as_deref()), Ok(1024)));
         }
     }
+
+    #[test]
+    fn test_stat_entries() {
+        let tmp = std::env::temp_dir().join("uu_du_test_stat_entries");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&tmp).unwrap();
+        for i in 0..32 {
+            fs::write(tmp.join(format!("file{i}")), b"x").unwrap();
+        }
+
+        let entries: Vec<_> = fs::read_dir(&tmp).unwrap().collect();
+        let options = TraversalOptions {
+            all: false,
+            separate_dirs: false,
+            one_file_system: false,
+            dereference: Deref::None,
+            count_links: false,
+            verbose: false,
+            excludes: Vec::new(),
+        };
+
+        let stats = stat_entries(&entries, &options);
+        assert_eq!(stats.len(), entries.len());
+        for stat in &stats {
+            assert!(stat.as_ref().unwrap().is_ok());
+        }
+
+        fs::remove_dir_all(&tmp).unwrap();
+    }
 }
@@ -19,7 +19,9 @@ use native_int_str::{
     from_native_int_representation_owned, Convert, NCvt, NativeIntStr, NativeIntString, NativeStr,
 };
 #[cfg(unix)]
-use nix::sys::signal::{raise, sigaction, SaFlags, SigAction, SigHandler, SigSet, Signal};
+use nix::sys::signal::{
+    raise, sigaction, sigprocmask, SaFlags, SigAction, SigHandler, SigSet, Signal, SigmaskHow,
+};
 use std::borrow::Cow;
 use std::env;
 use std::ffi::{OsStr, OsString};
@@ -49,6 +51,9 @@ struct Options<'a> {
     sets: Vec<(Cow<'a, OsStr>, Cow<'a, OsStr>)>,
     program: Vec<&'a OsStr>,
     argv0: Option<&'a OsStr>,
+    default_signals: Vec<Option<&'a OsStr>>,
+    ignore_signals: Vec<Option<&'a OsStr>>,
+    block_signals: Vec<Option<&'a OsStr>>,
 }
 
 // print name=value env pairs on screen
@@ -87,6 +92,19 @@ fn parse_program_opt<'a>(opts: &mut Options<'a>, opt: &'a OsStr) -> UResult<()>
     }
 }
 
+/// Collect the value (if any) given to each occurrence of a repeatable,
+/// optional-value signal flag (`--default-signal`, `--ignore-signal`,
+/// `--block-signal`). `None` in the result means that occurrence was given
+/// without a `SIG`, i.e. "all signals".
+fn collect_signal_specs<'a>(matches: &'a clap::ArgMatches, name: &str) -> Vec<Option<&'a OsStr>> {
+    match matches.get_occurrences::<OsString>(name) {
+        Some(occurrences) => occurrences
+            .map(|mut values| values.next().map(|s| s.as_os_str()))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
 fn load_config_file(opts: &mut Options) -> UResult<()> {
     // NOTE: config files are parsed using an INI parser b/c it's available and compatible with ".env"-style files
     //   ... * but support for actual INI files, although working, is not intended, nor claimed
@@ -201,6 +219,45 @@ pub fn uu_app() -> Command {
                 .action(ArgAction::Append)
                 .value_parser(ValueParser::os_string())
         )
+        .arg(
+            Arg::new("default-signal")
+                .long("default-signal")
+                .value_name("SIG")
+                .num_args(0..=1)
+                .require_equals(true)
+                .action(ArgAction::Append)
+                .value_parser(ValueParser::os_string())
+                .help(
+                    "reset handling of SIG signal(s) to the default; \
+                    with no SIG, reset all signals",
+                ),
+        )
+        .arg(
+            Arg::new("ignore-signal")
+                .long("ignore-signal")
+                .value_name("SIG")
+                .num_args(0..=1)
+                .require_equals(true)
+                .action(ArgAction::Append)
+                .value_parser(ValueParser::os_string())
+                .help(
+                    "set handling of SIG signal(s) to do nothing; \
+                    with no SIG, ignore all signals",
+                ),
+        )
+        .arg(
+            Arg::new("block-signal")
+                .long("block-signal")
+                .value_name("SIG")
+                .num_args(0..=1)
+                .require_equals(true)
+                .action(ArgAction::Append)
+                .value_parser(ValueParser::os_string())
+                .help(
+                    "block delivery of SIG signal(s) to COMMAND; \
+                    with no SIG, block all signals",
+                ),
+        )
 }
 
 pub fn parse_args_from_str(text: &NativeIntStr) -> UResult<Vec<NativeIntString>> {
@@ -395,6 +452,8 @@ impl EnvAppData {
          * standard library contains many checks and fail-safes to ensure the process ends up being
          * created. This is much simpler than dealing with the hassles of calling execvp directly.
          */
+        apply_signal_configuration(&opts)?;
+
         let mut cmd = process::Command::new(&*prog);
         cmd.args(args);
 
@@ -492,6 +551,9 @@ fn make_options(matches: &clap::ArgMatches) -> UResult<Options<'_>> {
         None => Vec::with_capacity(0),
     };
     let argv0 = matches.get_one::<OsString>("argv0").map(|s| s.as_os_str());
+    let default_signals = collect_signal_specs(matches, "default-signal");
+    let ignore_signals = collect_signal_specs(matches, "ignore-signal");
+    let block_signals = collect_signal_specs(matches, "block-signal");
 
     let mut opts = Options {
         ignore_env,
@@ -502,6 +564,9 @@ fn make_options(matches: &clap::ArgMatches) -> UResult<Options<'_>> {
         sets: vec![],
         program: vec![],
         argv0,
+        default_signals,
+        ignore_signals,
+        block_signals,
     };
 
     let mut begin_prog_opts = false;
@@ -569,6 +634,108 @@ fn apply_change_directory(opts: &Options<'_>) -> Result<(), Box<dyn UError>> {
     Ok(())
 }
 
+/// Resolve a single `--default-signal`/`--ignore-signal`/`--block-signal`
+/// occurrence into the signals it refers to. `None` (no `SIG` given) means
+/// every signal known to [`uucore::signals::ALL_SIGNALS`], except that
+/// `skip_sigchld` can be used to leave `SIGCHLD` out of that blanket set:
+/// unlike GNU env (which replaces itself with `execvp` and so never waits
+/// on the command it runs), we run COMMAND as a forked child and rely on
+/// `Command::status` to wait for it, which stops working once `SIGCHLD` is
+/// set to be ignored (the kernel auto-reaps the child instead).
+#[cfg(unix)]
+fn parse_signal_spec(spec: Option<&OsStr>, skip_sigchld: bool) -> UResult<Vec<Signal>> {
+    match spec {
+        None => Ok((1..uucore::signals::ALL_SIGNALS.len())
+            .filter(|&value| !(skip_sigchld && uucore::signals::ALL_SIGNALS[value] == "CHLD"))
+            .filter_map(|value| Signal::try_from(value as i32).ok())
+            .collect()),
+        Some(spec) => spec
+            .to_string_lossy()
+            .split(',')
+            .map(|name| {
+                uucore::signals::signal_by_name_or_value(name)
+                    .and_then(|value| Signal::try_from(value as i32).ok())
+                    .ok_or_else(|| {
+                        USimpleError::new(125, format!("{}: invalid signal", name.quote()))
+                            as Box<dyn UError>
+                    })
+            })
+            .collect(),
+    }
+}
+
+#[cfg(unix)]
+fn parse_signal_specs(specs: &[Option<&OsStr>], skip_sigchld: bool) -> UResult<Vec<Signal>> {
+    let mut signals = Vec::new();
+    for spec in specs {
+        signals.extend(parse_signal_spec(*spec, skip_sigchld)?);
+    }
+    Ok(signals)
+}
+
+/// Apply `--default-signal`, `--ignore-signal` and `--block-signal` to the
+/// current process. Since `Command::status` forks and execs without
+/// resetting signal dispositions or the signal mask in between, doing this
+/// on `env` itself before spawning the child has the same effect as GNU
+/// env's direct `sigaction`/`sigprocmask` calls just before `execvp`.
+///
+/// Each kind of flag is applied in full, in this fixed order: all
+/// `--default-signal` occurrences, then all `--ignore-signal` occurrences,
+/// then all `--block-signal` occurrences (which, being independent of
+/// disposition, is order-insensitive with respect to the other two). This
+/// only matters in practice if the same SIG is named by both
+/// `--default-signal` and `--ignore-signal`, in which case ignore wins
+/// regardless of the order the flags were given on the command line.
+#[cfg(unix)]
+fn apply_signal_configuration(opts: &Options<'_>) -> UResult<()> {
+    for signal in parse_signal_specs(&opts.default_signals, true)? {
+        // SAFETY: we are only resetting the handler to the default, not
+        // installing a function pointer.
+        let _ = unsafe {
+            sigaction(
+                signal,
+                &SigAction::new(SigHandler::SigDfl, SaFlags::empty(), SigSet::empty()),
+            )
+        };
+    }
+
+    for signal in parse_signal_specs(&opts.ignore_signals, true)? {
+        // SAFETY: SigIgn is not a function pointer, so there is nothing
+        // unsafe about installing it.
+        let _ = unsafe {
+            sigaction(
+                signal,
+                &SigAction::new(SigHandler::SigIgn, SaFlags::empty(), SigSet::empty()),
+            )
+        };
+    }
+
+    let block_signals = parse_signal_specs(&opts.block_signals, false)?;
+    if !block_signals.is_empty() {
+        let mut mask = SigSet::empty();
+        for signal in block_signals {
+            mask.add(signal);
+        }
+        sigprocmask(SigmaskHow::SIG_BLOCK, Some(&mask), None)
+            .map_err(|e| USimpleError::new(125, format!("failed to block signals: {e}")))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn apply_signal_configuration(opts: &Options<'_>) -> UResult<()> {
+    if !opts.default_signals.is_empty() || !opts.ignore_signals.is_empty()
+        || !opts.block_signals.is_empty()
+    {
+        return Err(USimpleError::new(
+            2,
+            "--default-signal, --ignore-signal and --block-signal are not supported on this platform",
+        ));
+    }
+    Ok(())
+}
+
 fn apply_specified_env_vars(opts: &Options<'_>) {
     // set specified env vars
     for (name, val) in &opts.sets {
@@ -6,17 +6,14 @@
 // spell-checker:ignore (ToDO) ctype cwidth iflag nbytes nspaces nums tspaces uflag Preprocess
 
 use clap::{crate_version, Arg, ArgAction, ArgMatches, Command};
-use std::error::Error;
 use std::ffi::OsString;
-use std::fmt;
 use std::fs::File;
 use std::io::{stdin, stdout, BufRead, BufReader, BufWriter, Read, Write};
-use std::num::IntErrorKind;
 use std::path::Path;
 use std::str::from_utf8;
 use unicode_width::UnicodeWidthChar;
-use uucore::display::Quotable;
-use uucore::error::{set_exit_code, FromIo, UError, UResult};
+use uucore::error::{set_exit_code, FromIo, UResult};
+use uucore::tabstops::{self, parse_tabstops as tabstops_parse, RemainingMode};
 use uucore::{format_usage, help_about, help_usage, show_error};
 
 const ABOUT: &str = help_about!("expand.md");
@@ -33,164 +30,11 @@ static LONG_HELP: &str = "";
 
 static DEFAULT_TABSTOP: usize = 8;
 
-/// The mode to use when replacing tabs beyond the last one specified in
-/// the `--tabs` argument.
-#[derive(PartialEq)]
-enum RemainingMode {
-    None,
-    Slash,
-    Plus,
-}
-
-/// Decide whether the character is either a space or a comma.
-///
-/// # Examples
-///
-/// ```rust,ignore
-/// assert!(is_space_or_comma(' '))
-/// assert!(is_space_or_comma(','))
-/// assert!(!is_space_or_comma('a'))
-/// ```
-fn is_space_or_comma(c: char) -> bool {
-    c == ' ' || c == ','
-}
-
 /// Decide whether the character is either a digit or a comma.
 fn is_digit_or_comma(c: char) -> bool {
     c.is_ascii_digit() || c == ','
 }
 
-/// Errors that can occur when parsing a `--tabs` argument.
-#[derive(Debug)]
-enum ParseError {
-    InvalidCharacter(String),
-    SpecifierNotAtStartOfNumber(String, String),
-    SpecifierOnlyAllowedWithLastValue(String),
-    TabSizeCannotBeZero,
-    TabSizeTooLarge(String),
-    TabSizesMustBeAscending,
-}
-
-impl Error for ParseError {}
-impl UError for ParseError {}
-
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::InvalidCharacter(s) => {
-                write!(f, "tab size contains invalid character(s): {}", s.quote())
-            }
-            Self::SpecifierNotAtStartOfNumber(specifier, s) => write!(
-                f,
-                "{} specifier not at start of number: {}",
-                specifier.quote(),
-                s.quote(),
-            ),
-            Self::SpecifierOnlyAllowedWithLastValue(specifier) => write!(
-                f,
-                "{} specifier only allowed with the last value",
-                specifier.quote()
-            ),
-            Self::TabSizeCannotBeZero => write!(f, "tab size cannot be 0"),
-            Self::TabSizeTooLarge(s) => write!(f, "tab stop is too large {}", s.quote()),
-            Self::TabSizesMustBeAscending => write!(f, "tab sizes must be ascending"),
-        }
-    }
-}
-
-/// Parse a list of tabstops from a `--tabs` argument.
-///
-/// This function returns both the vector of numbers appearing in the
-/// comma- or space-separated list, and also an optional mode, specified
-/// by either a "/" or a "+" character appearing before the final number
-/// in the list. This mode defines the strategy to use for computing the
-/// number of spaces to use for columns beyond the end of the tab stop
-/// list specified here.
-fn tabstops_parse(s: &str) -> Result<(RemainingMode, Vec<usize>), ParseError> {
-    // Leading commas and spaces are ignored.
-    let s = s.trim_start_matches(is_space_or_comma);
-
-    // If there were only commas and spaces in the string, just use the
-    // default tabstops.
-    if s.is_empty() {
-        return Ok((RemainingMode::None, vec![DEFAULT_TABSTOP]));
-    }
-
-    let mut nums = vec![];
-    let mut remaining_mode = RemainingMode::None;
-    let mut is_specifier_already_used = false;
-    for word in s.split(is_space_or_comma) {
-        let bytes = word.as_bytes();
-        for i in 0..bytes.len() {
-            match bytes[i] {
-                b'+' => remaining_mode = RemainingMode::Plus,
-                b'/' => remaining_mode = RemainingMode::Slash,
-                _ => {
-                    // Parse a number from the byte sequence.
-                    let s = from_utf8(&bytes[i..]).unwrap();
-                    match s.parse::<usize>() {
-                        Ok(num) => {
-                            // Tab size must be positive.
-                            if num == 0 {
-                                return Err(ParseError::TabSizeCannotBeZero);
-                            }
-
-                            // Tab sizes must be ascending.
-                            if let Some(last_stop) = nums.last() {
-                                if *last_stop >= num {
-                                    return Err(ParseError::TabSizesMustBeAscending);
-                                }
-                            }
-
-                            if is_specifier_already_used {
-                                let specifier = if remaining_mode == RemainingMode::Slash {
-                                    "/".to_string()
-                                } else {
-                                    "+".to_string()
-                                };
-                                return Err(ParseError::SpecifierOnlyAllowedWithLastValue(
-                                    specifier,
-                                ));
-                            } else if remaining_mode != RemainingMode::None {
-                                is_specifier_already_used = true;
-                            }
-
-                            // Append this tab stop to the list of all tabstops.
-                            nums.push(num);
-                            break;
-                        }
-                        Err(e) => {
-                            if *e.kind() == IntErrorKind::PosOverflow {
-                                return Err(ParseError::TabSizeTooLarge(s.to_string()));
-                            }
-
-                            let s = s.trim_start_matches(char::is_numeric);
-                            if s.starts_with('/') || s.starts_with('+') {
-                                return Err(ParseError::SpecifierNotAtStartOfNumber(
-                                    s[0..1].to_string(),
-                                    s.to_string(),
-                                ));
-                            } else {
-                                return Err(ParseError::InvalidCharacter(s.to_string()));
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-    // If no numbers could be parsed (for example, if `s` were "+,+,+"),
-    // then just use the default tabstops.
-    if nums.is_empty() {
-        nums = vec![DEFAULT_TABSTOP];
-    }
-
-    if nums.len() < 2 {
-        remaining_mode = RemainingMode::None;
-    }
-    Ok((remaining_mode, nums))
-}
-
 struct Options {
     files: Vec<String>,
     tabstops: Vec<usize>,
@@ -204,7 +48,7 @@ struct Options {
 }
 
 impl Options {
-    fn new(matches: &ArgMatches) -> Result<Self, ParseError> {
+    fn new(matches: &ArgMatches) -> Result<Self, tabstops::TabStopsParseError> {
         let (remaining_mode, tabstops) = match matches.get_many::<String>(options::TABS) {
             Some(s) => tabstops_parse(&s.map(|s| s.as_str()).collect::<Vec<_>>().join(","))?,
             None => (RemainingMode::None, vec![DEFAULT_TABSTOP]),
@@ -331,35 +175,11 @@ fn open(path: &str) -> UResult<BufReader<Box<dyn Read + 'static>>> {
 /// in the `tabstops` slice is interpreted as a relative number of
 /// spaces, which this function will return for every input value of
 /// `col` beyond the end of the second-to-last element of `tabstops`.
+///
+/// Beyond the end of `tabstops` in [`RemainingMode::None`], a single
+/// space is used instead of a real tabstop.
 fn next_tabstop(tabstops: &[usize], col: usize, remaining_mode: &RemainingMode) -> usize {
-    let num_tabstops = tabstops.len();
-    match remaining_mode {
-        RemainingMode::Plus => match tabstops[0..num_tabstops - 1].iter().find(|&&t| t > col) {
-            Some(t) => t - col,
-            None => {
-                let step_size = tabstops[num_tabstops - 1];
-                let last_fixed_tabstop = tabstops[num_tabstops - 2];
-                let characters_since_last_tabstop = col - last_fixed_tabstop;
-
-                let steps_required = 1 + characters_since_last_tabstop / step_size;
-                steps_required * step_size - characters_since_last_tabstop
-            }
-        },
-        RemainingMode::Slash => match tabstops[0..num_tabstops - 1].iter().find(|&&t| t > col) {
-            Some(t) => t - col,
-            None => tabstops[num_tabstops - 1] - col % tabstops[num_tabstops - 1],
-        },
-        RemainingMode::None => {
-            if num_tabstops == 1 {
-                tabstops[0] - col % tabstops[0]
-            } else {
-                match tabstops.iter().find(|&&t| t > col) {
-                    Some(t) => t - col,
-                    None => 1,
-                }
-            }
-        }
-    }
+    tabstops::next_tabstop(tabstops, col, *remaining_mode).unwrap_or(1)
 }
 
 #[derive(PartialEq, Eq, Debug)]
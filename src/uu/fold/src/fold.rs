@@ -9,6 +9,7 @@ use clap::{crate_version, Arg, ArgAction, Command};
 use std::fs::File;
 use std::io::{stdin, BufRead, BufReader, Read};
 use std::path::Path;
+use unicode_width::UnicodeWidthChar;
 use uucore::display::Quotable;
 use uucore::error::{FromIo, UResult, USimpleError};
 use uucore::{format_usage, help_about, help_usage};
@@ -201,7 +202,9 @@ fn fold_file_bytewise<T: Read>(mut file: BufReader<T>, spaces: bool, width: usiz
 ///
 /// By default `fold` treats tab, backspace, and carriage return specially:
 /// tab characters count as 8 columns, backspace decreases the
-/// column count, and carriage return resets the column count to 0.
+/// column count, and carriage return resets the column count to 0. All
+/// other characters are counted by their Unicode display width, so e.g. a
+/// wide CJK character counts as 2 columns rather than 1.
 ///
 /// If `spaces` is `true`, attempt to break lines at whitespace boundaries.
 #[allow(unused_assignments)]
@@ -274,9 +277,9 @@ fn fold_file<T: Read>(mut file: BufReader<T>, spaces: bool, width: usize) -> URe
                 }
                 _ if spaces && ch.is_whitespace() => {
                     last_space = Some(output.len());
-                    col_count += 1;
+                    col_count += UnicodeWidthChar::width(ch).unwrap_or(1);
                 }
-                _ => col_count += 1,
+                _ => col_count += UnicodeWidthChar::width(ch).unwrap_or(1),
             };
 
             output.push(ch);
@@ -21,7 +21,7 @@ use std::iter;
 use std::num::ParseIntError;
 use std::path::Path;
 use uucore::error::USimpleError;
-use uucore::error::{FromIo, UError, UResult};
+use uucore::error::{set_exit_code, FromIo, UError, UResult};
 use uucore::sum::{
     Blake2b, Blake3, Digest, DigestWriter, Md5, Sha1, Sha224, Sha256, Sha384, Sha3_224, Sha3_256,
     Sha3_384, Sha3_512, Sha512, Shake128, Shake256,
@@ -46,6 +46,7 @@ struct Options {
     warn: bool,
     output_bits: usize,
     zero: bool,
+    ignore_missing: bool,
 }
 
 /// Creates a Blake2b hasher instance based on the specified length argument.
@@ -345,6 +346,14 @@ pub fn uumain(mut args: impl uucore::Args) -> UResult<()> {
     let strict = matches.get_flag("strict");
     let warn = matches.get_flag("warn") && !status;
     let zero = matches.get_flag("zero");
+    let ignore_missing = matches.get_flag("ignore-missing");
+
+    if ignore_missing && !check {
+        return Err(USimpleError::new(
+            1,
+            "the --ignore-missing option is meaningful only when verifying checksums",
+        ));
+    }
 
     let opts = Options {
         algoname: name,
@@ -359,6 +368,7 @@ pub fn uumain(mut args: impl uucore::Args) -> UResult<()> {
         strict,
         warn,
         zero,
+        ignore_missing,
     };
 
     match matches.get_many::<OsString>("FILE") {
@@ -431,6 +441,12 @@ pub fn uu_app_common() -> Command {
                 .help("exit non-zero for improperly formatted checksum lines")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("ignore-missing")
+                .long("ignore-missing")
+                .help("don't fail or report status for missing files")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("warn")
                 .short('w')
@@ -586,6 +602,7 @@ where
     let mut bad_format = 0;
     let mut failed_cksum = 0;
     let mut failed_open_file = 0;
+    let mut properly_formatted_lines = 0;
     let binary_marker = if options.binary { "*" } else { " " };
     for filename in files {
         let filename = Path::new(filename);
@@ -705,6 +722,9 @@ where
                 let (ck_filename_unescaped, prefix) = unescape_filename(&ck_filename);
                 let f = match File::open(ck_filename_unescaped) {
                     Err(_) => {
+                        if options.ignore_missing {
+                            continue;
+                        }
                         failed_open_file += 1;
                         println!(
                             "{}: {}: No such file or directory",
@@ -716,6 +736,7 @@ where
                     }
                     Ok(file) => file,
                 };
+                properly_formatted_lines += 1;
                 let mut ckf = BufReader::new(Box::new(f) as Box<dyn Read>);
                 let real_sum = digest_reader(
                     &mut options.digest,
@@ -769,6 +790,12 @@ where
             }
         }
     }
+    if options.check && options.ignore_missing && properly_formatted_lines == 0 {
+        show_warning!("no file was verified");
+        set_exit_code(1);
+        return Ok(());
+    }
+
     if !options.status {
         match bad_format.cmp(&1) {
             Ordering::Equal => show_warning!("{} line is improperly formatted", bad_format),
@@ -787,6 +814,10 @@ where
         }
     }
 
+    if failed_cksum > 0 || failed_open_file > 0 {
+        set_exit_code(1);
+    }
+
     Ok(())
 }
 
@@ -47,6 +47,7 @@ pub struct Behavior {
     strip_program: String,
     create_leading: bool,
     target_dir: Option<String>,
+    context: Option<Option<String>>,
 }
 
 #[derive(Debug)]
@@ -66,6 +67,7 @@ enum InstallError {
     InvalidGroup(String),
     OmittingDirectory(PathBuf),
     NotADirectory(PathBuf),
+    SetSecurityContextFailed(PathBuf, String),
 }
 
 impl UError for InstallError {
@@ -123,6 +125,12 @@ impl Display for InstallError {
             Self::NotADirectory(dir) => {
                 write!(f, "failed to access {}: Not a directory", dir.quote())
             }
+            Self::SetSecurityContextFailed(file, msg) => write!(
+                f,
+                "failed to set default creation context of {}: {}",
+                file.quote(),
+                msg
+            ),
         }
     }
 }
@@ -313,13 +321,15 @@ pub fn uu_app() -> Command {
                 .action(ArgAction::SetTrue),
         )
         .arg(
-            // TODO implement flag
             Arg::new(OPT_CONTEXT)
                 .short('Z')
                 .long(OPT_CONTEXT)
-                .help("(unimplemented) set security context of files and directories")
+                .help(
+                    "set security context of files and directories, \
+                    falling back to the system default if CONTEXT is omitted",
+                )
                 .value_name("CONTEXT")
-                .action(ArgAction::SetTrue),
+                .num_args(0..=1),
         )
         .arg(
             Arg::new(ARG_FILES)
@@ -343,8 +353,6 @@ fn check_unimplemented(matches: &ArgMatches) -> UResult<()> {
         Err(InstallError::Unimplemented(String::from("--no-target-directory, -T")).into())
     } else if matches.get_flag(OPT_PRESERVE_CONTEXT) {
         Err(InstallError::Unimplemented(String::from("--preserve-context, -P")).into())
-    } else if matches.get_flag(OPT_CONTEXT) {
-        Err(InstallError::Unimplemented(String::from("--context, -Z")).into())
     } else {
         Ok(())
     }
@@ -441,6 +449,9 @@ fn behavior(matches: &ArgMatches) -> UResult<Behavior> {
         ),
         create_leading: matches.get_flag(OPT_CREATE_LEADING),
         target_dir,
+        context: matches
+            .contains_id(OPT_CONTEXT)
+            .then(|| matches.get_one::<String>(OPT_CONTEXT).cloned()),
     })
 }
 
@@ -491,6 +502,7 @@ fn directory(paths: &[String], b: &Behavior) -> UResult<()> {
             }
 
             show_if_err!(chown_optional_user_group(path, b));
+            show_if_err!(set_security_context(path, b));
         }
         // If the exit code was set, or show! has been called at least once
         // (which sets the exit code as well), function execution will end after
@@ -840,6 +852,20 @@ fn set_ownership_and_permissions(to: &Path, b: &Behavior) -> UResult<()> {
     Ok(())
 }
 
+/// Apply the `-Z`/`--context` security context, if requested, to the
+/// destination path.
+///
+/// Does nothing when `-Z` wasn't passed. When it was passed without a value,
+/// `to` is labeled with the system default context for its location;
+/// otherwise it's labeled with the explicit context string given.
+fn set_security_context(to: &Path, b: &Behavior) -> UResult<()> {
+    let Some(context) = &b.context else {
+        return Ok(());
+    };
+    uucore::selinux::set_security_context(to, context.as_deref())
+        .map_err(|e| InstallError::SetSecurityContextFailed(to.to_path_buf(), e).into())
+}
+
 /// Preserve timestamps on the destination file.
 ///
 /// # Parameters
@@ -897,6 +923,7 @@ fn copy(from: &Path, to: &Path, b: &Behavior) -> UResult<()> {
     }
 
     set_ownership_and_permissions(to, b)?;
+    set_security_context(to, b)?;
 
     if b.preserve_timestamps {
         preserve_timestamps(from, to)?;
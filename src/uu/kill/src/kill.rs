@@ -11,7 +11,7 @@ use nix::unistd::Pid;
 use std::io::Error;
 use uucore::display::Quotable;
 use uucore::error::{FromIo, UResult, USimpleError};
-use uucore::signals::{signal_by_name_or_value, ALL_SIGNALS};
+use uucore::signals::{signal_by_name_or_value, signal_name_by_value, ALL_SIGNALS};
 use uucore::{format_usage, help_about, help_usage, show};
 
 static ABOUT: &str = help_about!("kill.md");
@@ -155,6 +155,15 @@ fn print_signal(signal_name_or_value: &str) -> UResult<()> {
             return Ok(());
         }
     }
+    // A shell reports a process killed by a signal as exited with status
+    // 128 + signal number, so also accept that form, e.g. `kill -l 137`
+    // (128 + SIGKILL) should print "KILL".
+    if let Ok(value) = signal_name_or_value.parse::<usize>() {
+        if let Some(signal) = value.checked_sub(128).and_then(signal_name_by_value) {
+            println!("{signal}");
+            return Ok(());
+        }
+    }
     Err(USimpleError::new(
         1,
         format!("unknown signal name {}", signal_name_or_value.quote()),
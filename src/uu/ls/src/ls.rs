@@ -1135,16 +1135,7 @@ impl Config {
             indicator_style,
             time_style,
             context,
-            selinux_supported: {
-                #[cfg(feature = "selinux")]
-                {
-                    selinux::kernel_support() != selinux::KernelSupport::Unsupported
-                }
-                #[cfg(not(feature = "selinux"))]
-                {
-                    false
-                }
-            },
+            selinux_supported: uucore::selinux::is_selinux_enabled(),
             group_directories_first: options.get_flag(options::GROUP_DIRECTORIES_FIRST),
             line_ending: LineEnding::from_zero_flag(options.get_flag(options::ZERO)),
             dired,
@@ -3021,7 +3012,13 @@ fn display_date(metadata: &Metadata, config: &Config) -> String {
 
                     time.format(fmt)
                 }
-                TimeStyle::Format(e) => time.format(e),
+                TimeStyle::Format(e) => match e.split_once('\n') {
+                    // GNU ls treats a two-line +FORMAT as "recent-format\nolder-format".
+                    Some((recent_fmt, older_fmt)) => {
+                        time.format(if recent { recent_fmt } else { older_fmt })
+                    }
+                    None => time.format(e),
+                },
             }
             .to_string()
         }
@@ -3429,9 +3426,8 @@ fn display_inode(metadata: &Metadata) -> String {
 
 // This returns the SELinux security context as UTF8 `String`.
 // In the long term this should be changed to `OsStr`, see discussions at #2621/#2656
-#[allow(unused_variables)]
 fn get_security_context(config: &Config, p_buf: &Path, must_dereference: bool) -> String {
-    let substitute_string = "?".to_string();
+    let substitute_string = uucore::selinux::UNKNOWN_SECURITY_CONTEXT.to_string();
     // If we must dereference, ensure that the symlink is actually valid even if the system
     // does not support SELinux.
     // Conforms to the GNU coreutils where a dangling symlink results in exit code 1.
@@ -3446,37 +3442,17 @@ fn get_security_context(config: &Config, p_buf: &Path, must_dereference: bool) -
             Ok(md) => (),
         }
     }
-    if config.selinux_supported {
-        #[cfg(feature = "selinux")]
-        {
-            match selinux::SecurityContext::of_path(p_buf, must_dereference.to_owned(), false) {
-                Err(_r) => {
-                    // TODO: show the actual reason why it failed
-                    show_warning!("failed to get security context of: {}", p_buf.quote());
-                    substitute_string
-                }
-                Ok(None) => substitute_string,
-                Ok(Some(context)) => {
-                    let context = context.as_bytes();
-
-                    let context = context.strip_suffix(&[0]).unwrap_or(context);
-                    String::from_utf8(context.to_vec()).unwrap_or_else(|e| {
-                        show_warning!(
-                            "getting security context of: {}: {}",
-                            p_buf.quote(),
-                            e.to_string()
-                        );
-                        String::from_utf8_lossy(context).into_owned()
-                    })
-                }
-            }
-        }
-        #[cfg(not(feature = "selinux"))]
-        {
+    if !config.selinux_supported {
+        return substitute_string;
+    }
+    match uucore::selinux::get_security_context(p_buf, must_dereference) {
+        Err(e) => {
+            // TODO: show the actual reason why it failed
+            show_warning!("failed to get security context of: {}: {}", p_buf.quote(), e);
             substitute_string
         }
-    } else {
-        substitute_string
+        Ok(None) => substitute_string,
+        Ok(Some(context)) => context,
     }
 }
 
@@ -28,6 +28,8 @@ mod options {
     pub const MODE: &str = "mode";
     pub const PARENTS: &str = "parents";
     pub const VERBOSE: &str = "verbose";
+    pub const SE_LINUX_SECURITY_CONTEXT: &str = "Z";
+    pub const CONTEXT: &str = "context";
     pub const DIRS: &str = "dirs";
 }
 
@@ -80,11 +82,16 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     // a possible MODE prefix '-' needs to be removed (e.g. "chmod -x FILE").
     let mode_had_minus_prefix = strip_minus_from_mode(&mut args);
 
-    // Linux-specific options, not implemented
-    // opts.optflag("Z", "context", "set SELinux security context" +
-    // " of each created directory to CTX"),
     let matches = uu_app().after_help(AFTER_HELP).try_get_matches_from(args)?;
 
+    let context_given = matches.contains_id(options::CONTEXT);
+    let context = matches
+        .get_one::<String>(options::CONTEXT)
+        .map(|s| s.as_str());
+    if context_given || matches.get_flag(options::SE_LINUX_SECURITY_CONTEXT) {
+        uucore::selinux::set_fscreate_context(context).map_err(|e| USimpleError::new(1, e))?;
+    }
+
     let dirs = matches
         .get_many::<OsString>(options::DIRS)
         .unwrap_or_default();
@@ -123,6 +130,23 @@ pub fn uu_app() -> Command {
                 .help("print a message for each printed directory")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(options::SE_LINUX_SECURITY_CONTEXT)
+                .short('Z')
+                .help("set SELinux security context of each created directory to the default type")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(options::CONTEXT)
+                .long(options::CONTEXT)
+                .value_name("CTX")
+                .num_args(0..=1)
+                .require_equals(true)
+                .help(
+                    "like -Z, or if CTX is specified then set the SELinux \
+                    or SMACK security context to CTX",
+                ),
+        )
         .arg(
             Arg::new(options::DIRS)
                 .action(ArgAction::Append)
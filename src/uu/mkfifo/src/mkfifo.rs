@@ -24,11 +24,10 @@ mod options {
 pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let matches = uu_app().try_get_matches_from(args)?;
 
-    if matches.contains_id(options::CONTEXT) {
-        return Err(USimpleError::new(1, "--context is not implemented"));
-    }
-    if matches.get_flag(options::SE_LINUX_SECURITY_CONTEXT) {
-        return Err(USimpleError::new(1, "-Z is not implemented"));
+    let context_given = matches.contains_id(options::CONTEXT);
+    let context = matches.get_one::<String>(options::CONTEXT).map(|s| s.as_str());
+    if context_given || matches.get_flag(options::SE_LINUX_SECURITY_CONTEXT) {
+        uucore::selinux::set_fscreate_context(context).map_err(|e| USimpleError::new(1, e))?;
     }
 
     let mode = match matches.get_one::<String>(options::MODE) {
@@ -84,6 +83,8 @@ pub fn uu_app() -> Command {
             Arg::new(options::CONTEXT)
                 .long(options::CONTEXT)
                 .value_name("CTX")
+                .num_args(0..=1)
+                .require_equals(true)
                 .help(
                     "like -Z, or if CTX is specified then set the SELinux \
                     or SMACK security context to CTX",
@@ -5,7 +5,7 @@
 
 // spell-checker:ignore (ToDO) parsemode makedev sysmacros perror IFBLK IFCHR IFIFO
 
-use clap::{crate_version, value_parser, Arg, ArgMatches, Command};
+use clap::{crate_version, value_parser, Arg, ArgAction, ArgMatches, Command};
 use libc::{dev_t, mode_t};
 use libc::{S_IFBLK, S_IFCHR, S_IFIFO, S_IRGRP, S_IROTH, S_IRUSR, S_IWGRP, S_IWOTH, S_IWUSR};
 use std::ffi::CString;
@@ -14,6 +14,11 @@ use uucore::display::Quotable;
 use uucore::error::{set_exit_code, UResult, USimpleError, UUsageError};
 use uucore::{format_usage, help_about, help_section, help_usage};
 
+mod options {
+    pub const SE_LINUX_SECURITY_CONTEXT: &str = "Z";
+    pub const CONTEXT: &str = "context";
+}
+
 const ABOUT: &str = help_about!("mknod.md");
 const USAGE: &str = help_usage!("mknod.md");
 const AFTER_HELP: &str = help_section!("after help", "mknod.md");
@@ -68,12 +73,16 @@ fn _mknod(file_name: &str, mode: mode_t, dev: dev_t) -> i32 {
 
 #[uucore::main]
 pub fn uumain(args: impl uucore::Args) -> UResult<()> {
-    // Linux-specific options, not implemented
-    // opts.optflag("Z", "", "set the SELinux security context to default type");
-    // opts.optopt("", "context", "like -Z, or if CTX is specified then set the SELinux or SMACK security context to CTX");
-
     let matches = uu_app().try_get_matches_from(args)?;
 
+    let context_given = matches.contains_id(options::CONTEXT);
+    let context = matches
+        .get_one::<String>(options::CONTEXT)
+        .map(|s| s.as_str());
+    if context_given || matches.get_flag(options::SE_LINUX_SECURITY_CONTEXT) {
+        uucore::selinux::set_fscreate_context(context).map_err(|e| USimpleError::new(1, e))?;
+    }
+
     let mode = get_mode(&matches).map_err(|e| USimpleError::new(1, e))?;
 
     let file_name = matches
@@ -130,6 +139,23 @@ pub fn uu_app() -> Command {
                 .value_name("MODE")
                 .help("set file permission bits to MODE, not a=rw - umask"),
         )
+        .arg(
+            Arg::new(options::SE_LINUX_SECURITY_CONTEXT)
+                .short('Z')
+                .help("set the SELinux security context to default type")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(options::CONTEXT)
+                .long(options::CONTEXT)
+                .value_name("CTX")
+                .num_args(0..=1)
+                .require_equals(true)
+                .help(
+                    "like -Z, or if CTX is specified then set the SELinux \
+                    or SMACK security context to CTX",
+                ),
+        )
         .arg(
             Arg::new("name")
                 .value_name("NAME")
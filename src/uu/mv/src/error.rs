@@ -19,6 +19,9 @@ pub enum MvError {
     NotADirectory(String),
     TargetNotADirectory(String),
     FailedToAccessNotADirectory(String),
+    ExchangeNotTwoPaths,
+    #[cfg(not(target_os = "linux"))]
+    ExchangeUnsupported,
 }
 
 impl Error for MvError {}
@@ -49,6 +52,16 @@ impl Display for MvError {
             Self::FailedToAccessNotADirectory(t) => {
                 write!(f, "failed to access {t}: Not a directory")
             }
+            Self::ExchangeNotTwoPaths => {
+                write!(f, "--exchange requires exactly two paths")
+            }
+            #[cfg(not(target_os = "linux"))]
+            Self::ExchangeUnsupported => {
+                write!(
+                    f,
+                    "--exchange is not supported on this platform or filesystem"
+                )
+            }
         }
     }
 }
@@ -15,6 +15,7 @@ use std::env;
 use std::ffi::OsString;
 use std::fs;
 use std::io;
+use std::io::{Read, Write};
 #[cfg(unix)]
 use std::os::unix;
 #[cfg(windows)]
@@ -83,6 +84,9 @@ pub struct Options {
 
     /// '-g, --progress'
     pub progress_bar: bool,
+
+    /// '--exchange'
+    pub exchange: bool,
 }
 
 /// specifies behavior of the overwrite flag
@@ -108,6 +112,7 @@ static OPT_TARGET_DIRECTORY: &str = "target-directory";
 static OPT_NO_TARGET_DIRECTORY: &str = "no-target-directory";
 static OPT_VERBOSE: &str = "verbose";
 static OPT_PROGRESS: &str = "progress";
+static OPT_EXCHANGE: &str = "exchange";
 static ARG_FILES: &str = "files";
 
 #[uucore::main]
@@ -164,8 +169,13 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         verbose: matches.get_flag(OPT_VERBOSE),
         strip_slashes: matches.get_flag(OPT_STRIP_TRAILING_SLASHES),
         progress_bar: matches.get_flag(OPT_PROGRESS),
+        exchange: matches.get_flag(OPT_EXCHANGE),
     };
 
+    if opts.exchange && (files.len() != 2 || opts.target_dir.is_some()) {
+        return Err(MvError::ExchangeNotTwoPaths.into());
+    }
+
     mv(&files[..], &opts)
 }
 
@@ -248,6 +258,15 @@ pub fn uu_app() -> Command {
                 )
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(OPT_EXCHANGE)
+                .long(OPT_EXCHANGE)
+                .help(
+                    "atomically exchange SOURCE and DEST instead of moving SOURCE to DEST. \n\
+                Note: this feature is not supported by GNU coreutils.",
+                )
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(ARG_FILES)
                 .action(ArgAction::Append)
@@ -391,6 +410,16 @@ fn handle_multiple_paths(paths: &[PathBuf], opts: &Options) -> UResult<()> {
 pub fn mv(files: &[OsString], opts: &Options) -> UResult<()> {
     let paths = parse_paths(files, opts);
 
+    if opts.exchange {
+        return exchange_files(&paths[0], &paths[1]).map_err_context(|| {
+            format!(
+                "cannot exchange {} and {}",
+                paths[0].quote(),
+                paths[1].quote()
+            )
+        });
+    }
+
     if let Some(ref name) = opts.target_dir {
         return move_files_into_dir(&paths, &PathBuf::from(name), opts);
     }
@@ -401,6 +430,46 @@ pub fn mv(files: &[OsString], opts: &Options) -> UResult<()> {
     }
 }
 
+/// Atomically swap `first` and `second` in place.
+///
+/// Uses `renameat2(2)` with `RENAME_EXCHANGE` on Linux, so both paths must
+/// already exist and live on the same filesystem. There is no portable
+/// fallback: unlike a plain rename, an exchange can't be emulated with a
+/// copy-and-remove without a window where one of the two paths is missing.
+#[cfg(target_os = "linux")]
+fn exchange_files(first: &Path, second: &Path) -> io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let first = CString::new(first.as_os_str().as_bytes())?;
+    let second = CString::new(second.as_os_str().as_bytes())?;
+
+    let result = unsafe {
+        libc::syscall(
+            libc::SYS_renameat2,
+            libc::AT_FDCWD,
+            first.as_ptr(),
+            libc::AT_FDCWD,
+            second.as_ptr(),
+            libc::RENAME_EXCHANGE,
+        )
+    };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn exchange_files(_first: &Path, _second: &Path) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        MvError::ExchangeUnsupported.to_string(),
+    ))
+}
+
 #[allow(clippy::cognitive_complexity)]
 fn move_files_into_dir(files: &[PathBuf], target_dir: &Path, options: &Options) -> UResult<()> {
     // remember the moved destinations for further usage
@@ -661,13 +730,62 @@ fn rename_with_fallback(
                 };
             }
         } else {
+            let progress_bar = multi_progress.map(|multi_progress| {
+                let bar = ProgressBar::new(metadata.len()).with_style(
+                    ProgressStyle::with_template(
+                        "{msg}: [{elapsed_precise}] {wide_bar} {bytes:>7}/{total_bytes:7}",
+                    )
+                    .unwrap(),
+                );
+                multi_progress.add(bar)
+            });
+
             #[cfg(all(unix, not(any(target_os = "macos", target_os = "redox"))))]
-            fs::copy(from, to)
+            copy_file_with_progress(from, to, progress_bar.as_ref())
                 .and_then(|_| fsxattr::copy_xattrs(&from, &to))
                 .and_then(|_| fs::remove_file(from))?;
             #[cfg(any(target_os = "macos", target_os = "redox", not(unix)))]
-            fs::copy(from, to).and_then(|_| fs::remove_file(from))?;
+            copy_file_with_progress(from, to, progress_bar.as_ref())
+                .and_then(|_| fs::remove_file(from))?;
+        }
+    }
+    Ok(())
+}
+
+/// Copy a single file from `from` to `to`, reporting bytes copied on `progress_bar`.
+///
+/// Falls back to [`fs::copy`] when no progress bar is given, since that can
+/// take advantage of platform-specific fast paths (e.g. `copy_file_range`)
+/// that a manual read/write loop can't.
+fn copy_file_with_progress(
+    from: &Path,
+    to: &Path,
+    progress_bar: Option<&ProgressBar>,
+) -> io::Result<()> {
+    let Some(pb) = progress_bar else {
+        fs::copy(from, to)?;
+        return Ok(());
+    };
+
+    let mut src = fs::File::open(from)?;
+    let mut dst = fs::File::create(to)?;
+
+    pb.set_message(
+        from.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    );
+
+    let mut buf = [0u8; 256 * 1024];
+    let mut copied: u64 = 0;
+    loop {
+        let n = src.read(&mut buf)?;
+        if n == 0 {
+            break;
         }
+        dst.write_all(&buf[..n])?;
+        copied += n as u64;
+        pb.set_position(copied);
     }
     Ok(())
 }
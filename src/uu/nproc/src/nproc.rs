@@ -144,9 +144,101 @@ fn num_cpus_all() -> usize {
 
 // In some cases, thread::available_parallelism() may return an Err
 // In this case, we will return 1 (like GNU)
+//
+// On Linux, also cap the result to whatever CPU quota the current cgroup
+// (v1 or v2) has been given, so that e.g. a container limited to 2 CPUs
+// via `--cpus=2` doesn't get an nproc reporting the host's full core count.
 fn available_parallelism() -> usize {
-    match thread::available_parallelism() {
+    let n = match thread::available_parallelism() {
         Ok(n) => n.get(),
-        Err(_) => 1,
+        Err(_) => return 1,
+    };
+
+    #[cfg(target_os = "linux")]
+    if let Some(quota) = cgroup_cpu_quota() {
+        return std::cmp::min(n, std::cmp::max(quota, 1));
+    }
+
+    n
+}
+
+/// Read the number of CPUs allotted to the current process by a cgroup v2
+/// `cpu.max` or cgroup v1 `cpu.cfs_quota_us`/`cpu.cfs_period_us` pair.
+///
+/// Returns `None` if no cgroup CPU quota applies (e.g. "max"/unlimited, or
+/// the files can't be read, such as outside a container).
+#[cfg(target_os = "linux")]
+fn cgroup_cpu_quota() -> Option<usize> {
+    if let Some(n) = cgroup_v2_quota() {
+        return Some(n);
+    }
+    cgroup_v1_quota()
+}
+
+#[cfg(target_os = "linux")]
+fn cgroup_v2_quota() -> Option<usize> {
+    let contents = std::fs::read_to_string("/sys/fs/cgroup/cpu.max").ok()?;
+    parse_cgroup_v2_cpu_max(&contents)
+}
+
+#[cfg(target_os = "linux")]
+fn cgroup_v1_quota() -> Option<usize> {
+    let quota = std::fs::read_to_string("/sys/fs/cgroup/cpu/cpu.cfs_quota_us").ok()?;
+    let period = std::fs::read_to_string("/sys/fs/cgroup/cpu/cpu.cfs_period_us").ok()?;
+    parse_cgroup_v1_cfs_quota(&quota, &period)
+}
+
+/// Parse the contents of a cgroup v2 `cpu.max` file, which holds
+/// `"<quota> <period>"` (in microseconds), or `"max <period>"` when no
+/// quota is set.
+#[cfg(target_os = "linux")]
+fn parse_cgroup_v2_cpu_max(contents: &str) -> Option<usize> {
+    let mut fields = contents.split_whitespace();
+    let quota = fields.next()?;
+    let period: f64 = fields.next()?.parse().ok()?;
+    if quota == "max" {
+        return None;
+    }
+    let quota: f64 = quota.parse().ok()?;
+    Some((quota / period).ceil() as usize)
+}
+
+/// Parse the contents of the cgroup v1 `cpu.cfs_quota_us` and
+/// `cpu.cfs_period_us` files (in microseconds). A quota of `-1` means no
+/// limit is set.
+#[cfg(target_os = "linux")]
+fn parse_cgroup_v1_cfs_quota(quota: &str, period: &str) -> Option<usize> {
+    let quota: i64 = quota.trim().parse().ok()?;
+    if quota <= 0 {
+        return None;
+    }
+    let period: f64 = period.trim().parse().ok()?;
+    Some((quota as f64 / period).ceil() as usize)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::{parse_cgroup_v1_cfs_quota, parse_cgroup_v2_cpu_max};
+
+    #[test]
+    fn test_cgroup_v2_quota_limited() {
+        assert_eq!(parse_cgroup_v2_cpu_max("200000 100000\n"), Some(2));
+        // Round up a fractional CPU allotment.
+        assert_eq!(parse_cgroup_v2_cpu_max("150000 100000\n"), Some(2));
+    }
+
+    #[test]
+    fn test_cgroup_v2_quota_unlimited() {
+        assert_eq!(parse_cgroup_v2_cpu_max("max 100000\n"), None);
+    }
+
+    #[test]
+    fn test_cgroup_v1_quota_limited() {
+        assert_eq!(parse_cgroup_v1_cfs_quota("200000\n", "100000\n"), Some(2));
+    }
+
+    #[test]
+    fn test_cgroup_v1_quota_unlimited() {
+        assert_eq!(parse_cgroup_v1_cfs_quota("-1\n", "100000\n"), None);
     }
 }
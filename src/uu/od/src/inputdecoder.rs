@@ -152,6 +152,9 @@ impl<'a> MemoryDecoder<'a> {
             )),
             4 => f64::from(self.byte_order.read_f32(&self.data[start..start + 4])),
             8 => self.byte_order.read_f64(&self.data[start..start + 8]),
+            16 => crate::prn_float::decode_f128_bits(
+                self.byte_order.read_u128(&self.data[start..start + 16]),
+            ),
             _ => panic!("Invalid byte_size: {byte_size}"),
         }
     }
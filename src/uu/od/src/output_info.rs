@@ -11,7 +11,7 @@ use crate::formatteriteminfo::FormatterItemInfo;
 use crate::parse_formats::ParsedFormatterItemInfo;
 
 /// Size in bytes of the max datatype. ie set to 16 for 128-bit numbers.
-const MAX_BYTES_PER_UNIT: usize = 8;
+const MAX_BYTES_PER_UNIT: usize = 16;
 
 /// Contains information to output single output line in human readable form
 pub struct SpacedFormatterItemInfo {
@@ -217,7 +217,7 @@ fn test_calculate_alignment() {
 
     // the first line has no additional spacing:
     assert_eq!(
-        [0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 8,
@@ -229,7 +229,7 @@ fn test_calculate_alignment() {
     );
     // the second line a single space at the start of the block:
     assert_eq!(
-        [1, 0, 0, 0, 0, 0, 0, 0],
+        [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 4,
@@ -241,7 +241,7 @@ fn test_calculate_alignment() {
     );
     // the third line two spaces at pos 0, and 1 space at pos 4:
     assert_eq!(
-        [2, 0, 0, 0, 1, 0, 0, 0],
+        [2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 2,
@@ -259,7 +259,7 @@ fn test_calculate_alignment() {
     //  ff ff  ff ff  ff ff  ff ff  ff ff  ff ff  ff ff  ff ff
 
     assert_eq!(
-        [7, 0, 0, 0, 0, 0, 0, 0],
+        [7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 8,
@@ -270,7 +270,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [5, 0, 0, 0, 5, 0, 0, 0],
+        [5, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 4,
@@ -281,7 +281,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 2,
@@ -292,7 +292,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [1, 0, 1, 0, 1, 0, 1, 0],
+        [1, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -305,7 +305,7 @@ fn test_calculate_alignment() {
 
     // 9 tests where 8 .. 16 spaces are spread across 8 positions
     assert_eq!(
-        [1, 1, 1, 1, 1, 1, 1, 1],
+        [1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -316,7 +316,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [2, 1, 1, 1, 1, 1, 1, 1],
+        [2, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -327,7 +327,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [2, 1, 1, 1, 2, 1, 1, 1],
+        [2, 1, 1, 1, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -338,7 +338,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [3, 1, 1, 1, 2, 1, 1, 1],
+        [3, 1, 1, 1, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -349,7 +349,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [2, 1, 2, 1, 2, 1, 2, 1],
+        [2, 1, 2, 1, 2, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -360,7 +360,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [3, 1, 2, 1, 2, 1, 2, 1],
+        [3, 1, 2, 1, 2, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -371,7 +371,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [3, 1, 2, 1, 3, 1, 2, 1],
+        [3, 1, 2, 1, 3, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -382,7 +382,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [4, 1, 2, 1, 3, 1, 2, 1],
+        [4, 1, 2, 1, 3, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -393,7 +393,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [2, 2, 2, 2, 2, 2, 2, 2],
+        [2, 2, 2, 2, 2, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -406,7 +406,7 @@ fn test_calculate_alignment() {
 
     // 4 tests where 15 spaces are spread across 8, 4, 2 or 1 position(s)
     assert_eq!(
-        [4, 1, 2, 1, 3, 1, 2, 1],
+        [4, 1, 2, 1, 3, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 1,
@@ -417,7 +417,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [5, 0, 3, 0, 4, 0, 3, 0],
+        [5, 0, 3, 0, 4, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 2,
@@ -428,7 +428,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [8, 0, 0, 0, 7, 0, 0, 0],
+        [8, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 4,
@@ -439,7 +439,7 @@ fn test_calculate_alignment() {
         )
     );
     assert_eq!(
-        [15, 0, 0, 0, 0, 0, 0, 0],
+        [15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         OutputInfo::calculate_alignment(
             &TypeInfo {
                 byte_size: 8,
@@ -27,6 +27,12 @@ pub static FORMAT_ITEM_F64: FormatterItemInfo = FormatterItemInfo {
     formatter: FormatWriter::FloatWriter(format_item_flo64),
 };
 
+pub static FORMAT_ITEM_F128: FormatterItemInfo = FormatterItemInfo {
+    byte_size: 16,
+    print_width: 41,
+    formatter: FormatWriter::FloatWriter(format_item_flo128),
+};
+
 pub fn format_item_flo16(f: f64) -> String {
     format!(" {}", format_flo16(f16::from_f64(f)))
 }
@@ -39,6 +45,49 @@ pub fn format_item_flo64(f: f64) -> String {
     format!(" {}", format_flo64(f))
 }
 
+pub fn format_item_flo128(f: f64) -> String {
+    format!(" {}", format_flo128(f))
+}
+
+/// Decode a 16-byte IEEE 754 binary128 ("quad") value into the nearest `f64`.
+///
+/// `od -t fL` is meant to dump the platform's `long double`, which on most
+/// non-x86 platforms *is* binary128; we treat it that way unconditionally.
+/// Since Rust has no stable 128-bit float type, the mantissa is rounded down
+/// to the 52 bits an `f64` can hold, so the printed value carries `f64`
+/// precision rather than the full quad precision.
+pub fn decode_f128_bits(bits: u128) -> f64 {
+    let sign = (bits >> 127) & 1;
+    let exponent = ((bits >> 112) & 0x7fff) as i64;
+    let mantissa = bits & ((1u128 << 112) - 1);
+
+    let f64_bits: u64 = if exponent == 0x7fff {
+        // Infinity (zero mantissa) or NaN (nonzero mantissa).
+        let mantissa_bits = if mantissa == 0 { 0 } else { 1 };
+        (0x7ffu64 << 52) | mantissa_bits
+    } else if exponent == 0 {
+        // Zero or subnormal; binary128 subnormals are far smaller than the
+        // smallest subnormal f64, so they underflow to (signed) zero.
+        0
+    } else {
+        let f64_exponent = exponent - 16383 + 1023;
+        let mantissa_f64 = (mantissa >> (112 - 52)) as u64;
+        if f64_exponent >= 0x7ff {
+            0x7ffu64 << 52 // overflow to infinity
+        } else if f64_exponent <= 0 {
+            0 // underflow to zero
+        } else {
+            ((f64_exponent as u64) << 52) | mantissa_f64
+        }
+    };
+
+    f64::from_bits(((sign as u64) << 63) | f64_bits)
+}
+
+fn format_flo128(f: f64) -> String {
+    format_float(f, 40, 17)
+}
+
 fn format_flo16(f: f16) -> String {
     format_float(f64::from(f), 9, 4)
 }
@@ -222,3 +271,19 @@ fn test_format_flo16() {
     assert_eq!(format_flo16(f16::NEG_ZERO), "       -0");
     assert_eq!(format_flo16(f16::ZERO), "        0");
 }
+
+#[test]
+fn test_decode_f128_bits() {
+    const BIAS: u128 = 16383;
+    let quad = |sign: u128, exp: u128, mantissa: u128| (sign << 127) | (exp << 112) | mantissa;
+
+    assert_eq!(decode_f128_bits(quad(0, BIAS, 0)), 1.0);
+    assert_eq!(decode_f128_bits(quad(1, BIAS + 1, 0)), -2.0);
+    assert_eq!(decode_f128_bits(quad(0, 0, 0)), 0.0);
+    assert_eq!(decode_f128_bits(quad(1, 0, 0)), -0.0);
+    assert_eq!(decode_f128_bits(quad(0, 0x7fff, 0)), f64::INFINITY);
+    assert_eq!(decode_f128_bits(quad(1, 0x7fff, 0)), f64::NEG_INFINITY);
+    assert!(decode_f128_bits(quad(0, 0x7fff, 1)).is_nan());
+    // A binary128 subnormal is far smaller than the smallest f64 subnormal.
+    assert_eq!(decode_f128_bits(quad(0, 0, 1)), 0.0);
+}
@@ -6,6 +6,7 @@
 // spell-checker:ignore (path) eacces inacc
 
 use clap::{builder::ValueParser, crate_version, parser::ValueSource, Arg, ArgAction, Command};
+use rayon::prelude::*;
 use std::collections::VecDeque;
 use std::ffi::{OsStr, OsString};
 use std::fs::{self, File, Metadata};
@@ -53,11 +54,12 @@ pub struct Options {
     /// If no other option sets this mode, [`InteractiveMode::PromptProtected`]
     /// is used
     pub interactive: InteractiveMode,
-    #[allow(dead_code)]
     /// `--one-file-system`
     pub one_fs: bool,
-    /// `--preserve-root`/`--no-preserve-root`
+    /// `--preserve-root`/`--no-preserve-root`/`--preserve-root=all`
     pub preserve_root: bool,
+    /// `--preserve-root=all`
+    pub preserve_root_all: bool,
     /// `-r`, `--recursive`
     pub recursive: bool,
     /// `-d`, `--dir`
@@ -138,6 +140,9 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             },
             one_fs: matches.get_flag(OPT_ONE_FILE_SYSTEM),
             preserve_root: !matches.get_flag(OPT_NO_PRESERVE_ROOT),
+            preserve_root_all: matches
+                .get_one::<String>(OPT_PRESERVE_ROOT)
+                .is_some_and(|val| val == "all"),
             recursive: matches.get_flag(OPT_RECURSIVE),
             dir: matches.get_flag(OPT_DIR),
             verbose: matches.get_flag(OPT_VERBOSE),
@@ -216,8 +221,7 @@ pub fn uu_app() -> Command {
                 .long(OPT_ONE_FILE_SYSTEM)
                 .help(
                     "when removing a hierarchy recursively, skip any directory that is on a file \
-                    system different from that of the corresponding command line argument (NOT \
-                    IMPLEMENTED)",
+                    system different from that of the corresponding command line argument",
                 ).action(ArgAction::SetTrue),
         )
         .arg(
@@ -229,8 +233,13 @@ pub fn uu_app() -> Command {
         .arg(
             Arg::new(OPT_PRESERVE_ROOT)
                 .long(OPT_PRESERVE_ROOT)
-                .help("do not remove '/' (default)")
-                .action(ArgAction::SetTrue),
+                .value_name("all")
+                .num_args(0..=1)
+                .require_equals(true)
+                .help(
+                    "do not remove '/' (default); with 'all', reject any command line argument \
+                    on a separate device from its parent",
+                ),
         )
         .arg(
             Arg::new(OPT_RECURSIVE)
@@ -278,7 +287,6 @@ pub fn uu_app() -> Command {
         )
 }
 
-// TODO: implement one-file-system (this may get partially implemented in walkdir)
 /// Remove (or unlink) the given files
 ///
 /// Returns true if it has encountered an error.
@@ -327,32 +335,29 @@ fn handle_dir(path: &Path, options: &Options) -> bool {
     let mut had_err = false;
 
     let is_root = path.has_root() && path.parent().is_none();
+    if options.recursive
+        && options.preserve_root_all
+        && path
+            .parent()
+            .and_then(|parent| dev_of(path).zip(dev_of(parent)))
+            .is_some_and(|(dev, parent_dev)| dev != parent_dev)
+    {
+        show_error!(
+            "{}: is on a different filesystem than its parent directory; skipping",
+            path.quote()
+        );
+        return true;
+    }
     if options.recursive && (!is_root || !options.preserve_root) {
-        if options.interactive != InteractiveMode::Always && !options.verbose {
-            if let Err(e) = fs::remove_dir_all(path) {
-                // GNU compatibility (rm/empty-inacc.sh)
-                // remove_dir_all failed. maybe it is because of the permissions
-                // but if the directory is empty, remove_dir might work.
-                // So, let's try that before failing for real
-                if fs::remove_dir(path).is_err() {
-                    had_err = true;
-                    if e.kind() == std::io::ErrorKind::PermissionDenied {
-                        // GNU compatibility (rm/fail-eacces.sh)
-                        // here, GNU doesn't use some kind of remove_dir_all
-                        // It will show directory+file
-                        show_error!("cannot remove {}: {}", path.quote(), "Permission denied");
-                    } else {
-                        show_error!("cannot remove {}: {}", path.quote(), e);
-                    }
-                }
-            }
+        if options.interactive != InteractiveMode::Always && !options.verbose && !options.one_fs {
+            had_err = remove_dir_all_parallel(path).bitor(had_err);
         } else {
             let mut dirs: VecDeque<DirEntry> = VecDeque::new();
             // The Paths to not descend into. We need to this because WalkDir doesn't have a way, afaik, to not descend into a directory
             // So we have to just ignore paths as they come up if they start with a path we aren't descending into
             let mut not_descended: Vec<PathBuf> = Vec::new();
 
-            'outer: for entry in WalkDir::new(path) {
+            'outer: for entry in WalkDir::new(path).same_file_system(options.one_fs) {
                 match entry {
                     Ok(entry) => {
                         if options.interactive == InteractiveMode::Always {
@@ -409,6 +414,69 @@ fn handle_dir(path: &Path, options: &Options) -> bool {
     had_err
 }
 
+/// Recursively remove the contents of `path`, then `path` itself.
+///
+/// This is the non-interactive, non-verbose fast path, used for trees that
+/// may contain a very large number of entries. `path`'s immediate children
+/// are removed concurrently on a thread pool, which captures most of the
+/// real-world win for wide trees (e.g. a directory with millions of
+/// siblings) without the complexity of a fully parallel, multi-level
+/// traversal. Errors are collected and reported sorted by path so that the
+/// output doesn't depend on which thread happened to finish first.
+fn remove_dir_all_parallel(path: &Path) -> bool {
+    let read_dir = match fs::read_dir(path) {
+        Ok(read_dir) => read_dir,
+        Err(e) => return remove_dir_after_failed_read(path, e),
+    };
+    let entries: Vec<PathBuf> = read_dir.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+
+    let mut errors: Vec<(PathBuf, std::io::Error)> = entries
+        .par_iter()
+        .filter_map(|entry_path| {
+            let result = if entry_path.is_symlink() || !entry_path.is_dir() {
+                fs::remove_file(entry_path)
+            } else {
+                fs::remove_dir_all(entry_path)
+            };
+            result.err().map(|e| (entry_path.clone(), e))
+        })
+        .collect();
+
+    if !errors.is_empty() {
+        errors.sort_by(|a, b| a.0.cmp(&b.0));
+        for (entry_path, e) in &errors {
+            show_error!("cannot remove {}: {}", entry_path.quote(), e);
+        }
+        return true;
+    }
+
+    if let Err(e) = fs::remove_dir(path) {
+        show_error!("cannot remove {}: {}", path.quote(), e);
+        return true;
+    }
+
+    false
+}
+
+/// GNU compatibility (rm/empty-inacc.sh): reading `path`'s contents failed,
+/// maybe because of the permissions, but if the directory is actually empty
+/// `remove_dir` might still work. Try that before failing for real.
+fn remove_dir_after_failed_read(path: &Path, read_err: std::io::Error) -> bool {
+    if fs::remove_dir(path).is_err() {
+        if read_err.kind() == std::io::ErrorKind::PermissionDenied {
+            // GNU compatibility (rm/fail-eacces.sh)
+            // here, GNU doesn't use some kind of remove_dir_all
+            // It will show directory+file
+            show_error!("cannot remove {}: {}", path.quote(), "Permission denied");
+        } else {
+            show_error!("cannot remove {}: {}", path.quote(), read_err);
+        }
+        true
+    } else {
+        false
+    }
+}
+
 fn remove_dir(path: &Path, options: &Options) -> bool {
     if prompt_dir(path, options) {
         if let Ok(mut read_dir) = fs::read_dir(path) {
@@ -598,6 +666,20 @@ fn normalize(path: &Path) -> PathBuf {
     uucore::fs::normalize_path(path)
 }
 
+/// Returns the device number of the file system containing `path`, or
+/// `None` if it can't be determined (e.g. the path doesn't exist, or we're
+/// not on a platform that exposes this concept).
+#[cfg(unix)]
+fn dev_of(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    fs::metadata(path).ok().map(|m| m.dev())
+}
+
+#[cfg(not(unix))]
+fn dev_of(_path: &Path) -> Option<u64> {
+    None
+}
+
 #[cfg(not(windows))]
 fn is_symlink_dir(_metadata: &Metadata) -> bool {
     false
@@ -28,6 +28,29 @@ pub enum SeqError {
 
     /// No arguments were passed to this function, 1 or more is required
     NoArguments,
+
+    /// An auxiliary option (e.g. `--grid-origin`, `--grid-step`, or
+    /// `--duration-base`) could not be parsed as a number.
+    ///
+    /// The parameter is the argument as read from the command line.
+    InvalidNumericOption(String),
+
+    /// `--down` was given together with more than one operand.
+    DownMultipleOperands,
+
+    /// [`crate::seq_range`] was called with both `equal_width` and a
+    /// custom `format` set.
+    ///
+    /// The two conflict: a custom format string already controls how
+    /// each value is rendered, so an equal-width padding pass on top of
+    /// it has no well-defined meaning.
+    FormatWithEqualWidth,
+
+    /// A [`crate::seq_range`] operand could not be represented as a
+    /// finite `f64`, which the custom-format rendering path requires.
+    ///
+    /// The parameter is the operand, rendered as a decimal string.
+    InvalidFloat(String),
 }
 
 impl UError for SeqError {
@@ -56,6 +79,25 @@ impl Display for SeqError {
             }
             Self::ZeroIncrement(s) => write!(f, "invalid Zero increment value: {}", s.quote()),
             Self::NoArguments => write!(f, "missing operand"),
+            Self::InvalidNumericOption(s) => {
+                write!(f, "invalid numeric argument: {}", s.quote())
+            }
+            Self::DownMultipleOperands => {
+                write!(f, "--down requires exactly one operand")
+            }
+            Self::FormatWithEqualWidth => {
+                write!(
+                    f,
+                    "the equal-width and format options cannot be used together"
+                )
+            }
+            Self::InvalidFloat(s) => {
+                write!(
+                    f,
+                    "{} cannot be represented as a finite floating point number",
+                    s.quote()
+                )
+            }
         }
     }
 }
@@ -22,7 +22,7 @@
 //! ```
 use std::cmp::Ordering;
 use std::fmt::Display;
-use std::ops::Add;
+use std::ops::{Add, AddAssign};
 
 use bigdecimal::BigDecimal;
 use num_traits::Zero;
@@ -135,6 +135,33 @@ impl Add for ExtendedBigDecimal {
     }
 }
 
+/// Augmenting addition, i.e. `self += other`.
+///
+/// Unlike [`Add::add`], this only ever clones `other`'s underlying
+/// [`BigDecimal`] into `self` (via [`BigDecimal`]'s own `AddAssign`); it
+/// never allocates a new [`ExtendedBigDecimal`] for the common case of
+/// two finite operands. This matters for `seq`'s hot loop, which used to
+/// call `value = value + increment.clone()` on every term.
+impl AddAssign<&Self> for ExtendedBigDecimal {
+    fn add_assign(&mut self, other: &Self) {
+        match (&mut *self, other) {
+            (Self::BigDecimal(m), Self::BigDecimal(n)) => *m += n,
+            (Self::BigDecimal(_), Self::MinusInfinity) => *self = Self::MinusInfinity,
+            (Self::BigDecimal(_), Self::Infinity) => *self = Self::Infinity,
+            (Self::BigDecimal(_), Self::Nan) => *self = Self::Nan,
+            (Self::BigDecimal(_), Self::MinusZero) => {}
+            (Self::Infinity, Self::MinusInfinity) => *self = Self::Nan,
+            (Self::Infinity, Self::Nan) => *self = Self::Nan,
+            (Self::Infinity, _) => {}
+            (Self::MinusInfinity, Self::Infinity) => *self = Self::Nan,
+            (Self::MinusInfinity, Self::Nan) => *self = Self::Nan,
+            (Self::MinusInfinity, _) => {}
+            (Self::Nan, _) => {}
+            (Self::MinusZero, other) => *self = other.clone(),
+        }
+    }
+}
+
 impl PartialEq for ExtendedBigDecimal {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -163,6 +190,11 @@ impl PartialEq for ExtendedBigDecimal {
     }
 }
 
+/// Orders `MinusInfinity < MinusZero == BigDecimal(0) < finite < Infinity`.
+///
+/// `Nan` is never ordered relative to anything, including itself; callers
+/// that need a total order (for example the range loop in `done_printing`)
+/// must reject `Nan` operands earlier instead of relying on this impl.
 impl PartialOrd for ExtendedBigDecimal {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (self, other) {
@@ -194,6 +226,8 @@ impl PartialOrd for ExtendedBigDecimal {
 #[cfg(test)]
 mod tests {
 
+    use std::cmp::Ordering;
+
     use bigdecimal::BigDecimal;
     use num_traits::Zero;
 
@@ -224,6 +258,136 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_addition_infinity_and_minus_infinity_is_nan() {
+        let sum = ExtendedBigDecimal::Infinity + ExtendedBigDecimal::MinusInfinity;
+        match sum {
+            ExtendedBigDecimal::Nan => (),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_addition_infinity_is_absorbing() {
+        assert_eq!(
+            ExtendedBigDecimal::Infinity + ExtendedBigDecimal::Infinity,
+            ExtendedBigDecimal::Infinity
+        );
+        assert_eq!(
+            ExtendedBigDecimal::MinusInfinity + ExtendedBigDecimal::MinusInfinity,
+            ExtendedBigDecimal::MinusInfinity
+        );
+    }
+
+    #[test]
+    fn test_addition_minus_zero_identity() {
+        assert_eq!(
+            ExtendedBigDecimal::MinusZero + ExtendedBigDecimal::MinusZero,
+            ExtendedBigDecimal::MinusZero
+        );
+        let sum =
+            ExtendedBigDecimal::MinusZero + ExtendedBigDecimal::BigDecimal(BigDecimal::zero());
+        assert_eq!(sum, ExtendedBigDecimal::BigDecimal(BigDecimal::zero()));
+    }
+
+    #[test]
+    fn test_add_assign_matches_add() {
+        // `+=` should behave exactly like `+` for every combination this
+        // enum can represent.
+        let operands = [
+            ExtendedBigDecimal::BigDecimal(BigDecimal::from(3)),
+            ExtendedBigDecimal::Infinity,
+            ExtendedBigDecimal::MinusInfinity,
+            ExtendedBigDecimal::MinusZero,
+            ExtendedBigDecimal::Nan,
+        ];
+        for a in &operands {
+            for b in &operands {
+                let mut sum_assign = a.clone();
+                sum_assign += b;
+                let sum_add = a.clone() + b.clone();
+                match (sum_assign, sum_add) {
+                    (ExtendedBigDecimal::Nan, ExtendedBigDecimal::Nan) => (),
+                    (x, y) => assert_eq!(x, y, "mismatch for {a:?} += {b:?}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_assign_accumulates_without_reassignment() {
+        let mut total = ExtendedBigDecimal::BigDecimal(BigDecimal::zero());
+        let increment = ExtendedBigDecimal::BigDecimal(BigDecimal::from(2));
+        for _ in 0..5 {
+            total += &increment;
+        }
+        assert_eq!(total, ExtendedBigDecimal::BigDecimal(BigDecimal::from(10)));
+    }
+
+    #[test]
+    fn test_ord_minus_infinity_is_smallest() {
+        // `MinusZero` and `BigDecimal(0)` are equal, so the ordering is
+        // non-strict (`<=`) at that one point; everywhere else it is strict.
+        let values = [
+            ExtendedBigDecimal::MinusInfinity,
+            ExtendedBigDecimal::MinusZero,
+            ExtendedBigDecimal::BigDecimal(BigDecimal::zero()),
+            ExtendedBigDecimal::BigDecimal(BigDecimal::from(5)),
+            ExtendedBigDecimal::Infinity,
+        ];
+        for i in 0..values.len() {
+            for j in (i + 1)..values.len() {
+                assert!(
+                    values[i] <= values[j],
+                    "expected {:?} <= {:?}",
+                    values[i],
+                    values[j]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ord_minus_zero_equals_zero() {
+        assert_eq!(
+            ExtendedBigDecimal::MinusZero
+                .partial_cmp(&ExtendedBigDecimal::BigDecimal(BigDecimal::zero())),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            ExtendedBigDecimal::BigDecimal(BigDecimal::zero())
+                .partial_cmp(&ExtendedBigDecimal::MinusZero),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn test_ord_infinity_equals_itself() {
+        assert_eq!(
+            ExtendedBigDecimal::Infinity.partial_cmp(&ExtendedBigDecimal::Infinity),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            ExtendedBigDecimal::MinusInfinity.partial_cmp(&ExtendedBigDecimal::MinusInfinity),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn test_ord_nan_is_never_ordered() {
+        let others = [
+            ExtendedBigDecimal::Nan,
+            ExtendedBigDecimal::Infinity,
+            ExtendedBigDecimal::MinusInfinity,
+            ExtendedBigDecimal::MinusZero,
+            ExtendedBigDecimal::BigDecimal(BigDecimal::zero()),
+        ];
+        for other in &others {
+            assert_eq!(ExtendedBigDecimal::Nan.partial_cmp(other), None);
+            assert_eq!(other.partial_cmp(&ExtendedBigDecimal::Nan), None);
+        }
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(
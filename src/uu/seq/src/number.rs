@@ -3,10 +3,50 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 // spell-checker:ignore extendedbigdecimal
+use num_bigint::BigInt;
 use num_traits::Zero;
 
 use crate::extendedbigdecimal::ExtendedBigDecimal;
 
+/// The kind of number represented by a [`PreciseNumber`], used to decide
+/// between the exact integer fast path and the decimal/float path.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Number {
+    /// An exact arbitrary precision integer, for example `5` or `0x10`.
+    Integer(BigInt),
+
+    /// An arbitrary precision decimal number, for example `5.0` or `5e2`.
+    Decimal,
+
+    /// Positive infinity.
+    Infinity,
+
+    /// Negative infinity.
+    MinusInfinity,
+
+    /// Not a number.
+    Nan,
+}
+
+impl From<&ExtendedBigDecimal> for Number {
+    fn from(value: &ExtendedBigDecimal) -> Self {
+        match value {
+            ExtendedBigDecimal::BigDecimal(bd) => {
+                let (digits, scale) = bd.as_bigint_and_exponent();
+                if scale <= 0 {
+                    Self::Integer(digits * BigInt::from(10).pow((-scale) as u32))
+                } else {
+                    Self::Decimal
+                }
+            }
+            ExtendedBigDecimal::MinusZero => Self::Integer(BigInt::zero()),
+            ExtendedBigDecimal::Infinity => Self::Infinity,
+            ExtendedBigDecimal::MinusInfinity => Self::MinusInfinity,
+            ExtendedBigDecimal::Nan => Self::Nan,
+        }
+    }
+}
+
 /// A number with a specified number of integer and fractional digits.
 ///
 /// This struct can be used to represent a number along with information
@@ -50,4 +90,44 @@ impl PreciseNumber {
         // implement that here.
         self.number.is_zero()
     }
+
+    /// Decide whether this number is an exact integer with no fractional
+    /// part, taking into account both its value and how it was spelled
+    /// (`5.0` has a fractional part even though its value is exact).
+    pub fn is_exact_integer(&self) -> bool {
+        self.num_fractional_digits == 0 && matches!(Number::from(&self.number), Number::Integer(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use crate::number::{Number, PreciseNumber};
+
+    fn classify(s: &str) -> Number {
+        Number::from(&s.parse::<PreciseNumber>().unwrap().number)
+    }
+
+    #[test]
+    fn test_number_integer() {
+        assert_eq!(classify("5"), Number::Integer(BigInt::from(5)));
+    }
+
+    #[test]
+    fn test_number_decimal_with_trailing_zero() {
+        // "5.0" is exactly the integer 5, but it is spelled with a
+        // decimal point so the fast integer path does not apply.
+        assert_eq!(classify("5.0"), Number::Decimal);
+    }
+
+    #[test]
+    fn test_number_exponent() {
+        assert_eq!(classify("5e2"), Number::Integer(BigInt::from(500)));
+    }
+
+    #[test]
+    fn test_number_infinity() {
+        assert_eq!(classify("inf"), Number::Infinity);
+    }
 }
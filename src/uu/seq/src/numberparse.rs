@@ -26,6 +26,18 @@ pub enum ParseNumberError {
     Hex,
 }
 
+/// A sane upper bound on the number of fractional digits we will ever
+/// display for a single operand.
+///
+/// Operands are still parsed and computed on exactly regardless of how
+/// extreme their exponent is (e.g. `1e-4000`), since [`BigDecimal`]
+/// tracks a digit string and a scale rather than a materialized
+/// decimal string. But the *displayed* precision derived from that
+/// exponent is capped here, so that formatting a single value can
+/// never try to allocate an unbounded amount of memory for a
+/// pathological exponent like `1e-4000000000`.
+const MAX_FRACTIONAL_DIGITS: usize = 1_000_000;
+
 /// Decide whether a given string and its parsed `BigInt` is negative zero.
 fn is_minus_zero_int(s: &str, n: &BigDecimal) -> bool {
     s.starts_with('-') && n == &BigDecimal::zero()
@@ -36,6 +48,18 @@ fn is_minus_zero_float(s: &str, x: &BigDecimal) -> bool {
     s.starts_with('-') && x == &BigDecimal::zero()
 }
 
+/// Decide whether a lowercased string is a C99 "NaN" literal.
+///
+/// glibc's `strtod` (and therefore GNU `seq`) accepts a bare `nan`, as
+/// well as `nan(n-char-sequence)` with an arbitrary (possibly empty)
+/// parenthesized payload, e.g. `nan(123)`. Anything else that merely
+/// starts with "nan" (like `nan1` or `nanabc`) is not a NaN literal and
+/// falls through to the generic "invalid floating point argument" error.
+fn is_nan_literal(lower: &str) -> bool {
+    let s = lower.strip_prefix('-').unwrap_or(lower);
+    s == "nan" || (s.starts_with("nan(") && s.ends_with(')'))
+}
+
 /// Parse a number with neither a decimal point nor an exponent.
 ///
 /// # Errors
@@ -71,10 +95,11 @@ fn parse_no_decimal_no_exponent(s: &str) -> Result<PreciseNumber, ParseNumberErr
         }
         Err(_) => {
             // Possibly "NaN" or "inf".
-            let float_val = match s.to_ascii_lowercase().as_str() {
+            let lower = s.to_ascii_lowercase();
+            let float_val = match lower.as_str() {
                 "inf" | "infinity" => ExtendedBigDecimal::Infinity,
                 "-inf" | "-infinity" => ExtendedBigDecimal::MinusInfinity,
-                "nan" | "-nan" => return Err(ParseNumberError::Nan),
+                _ if is_nan_literal(&lower) => return Err(ParseNumberError::Nan),
                 _ => return Err(ParseNumberError::Float),
             };
             Ok(PreciseNumber::new(float_val, 0, 0))
@@ -111,7 +136,16 @@ fn parse_exponent_no_decimal(s: &str, j: usize) -> Result<PreciseNumber, ParseNu
             2usize
         }
     } else {
-        let total = j as i64 + exponent;
+        // `j` is the index of the `e` in the whole string, including a
+        // leading minus sign if there is one, so the number of digits
+        // in the integral part (the sign excluded) is `j` itself for a
+        // positive number but `j - 1` for a negative one.
+        let digits_before_e = if x.sign() == Sign::Minus {
+            j as i64 - 1
+        } else {
+            j as i64
+        };
+        let total = digits_before_e + exponent;
         let result = if total < 1 {
             1
         } else {
@@ -123,7 +157,11 @@ fn parse_exponent_no_decimal(s: &str, j: usize) -> Result<PreciseNumber, ParseNu
             result
         }
     };
-    let num_fractional_digits = if exponent < 0 { -exponent as usize } else { 0 };
+    let num_fractional_digits = if exponent < 0 {
+        (-exponent as usize).min(MAX_FRACTIONAL_DIGITS)
+    } else {
+        0
+    };
 
     if is_minus_zero_float(s, &x) {
         Ok(PreciseNumber::new(
@@ -232,9 +270,10 @@ fn parse_decimal_and_exponent(
     let num_fractional_digits = if num_digits_between_decimal_point_and_e < exponent {
         0
     } else {
-        (num_digits_between_decimal_point_and_e - exponent)
+        let digits: usize = (num_digits_between_decimal_point_and_e - exponent)
             .try_into()
-            .unwrap()
+            .unwrap();
+        digits.min(MAX_FRACTIONAL_DIGITS)
     };
 
     if is_minus_zero_float(s, &val) {
@@ -280,6 +319,10 @@ fn parse_hexadecimal(s: &str) -> Result<PreciseNumber, ParseNumberError> {
         return Err(ParseNumberError::Float);
     }
 
+    if s.contains('.') || s.to_lowercase().contains('p') {
+        return parse_hexadecimal_float(is_neg, s);
+    }
+
     let num = BigInt::from_str_radix(s, 16).map_err(|_| ParseNumberError::Hex)?;
     let num = BigDecimal::from(num);
 
@@ -298,6 +341,99 @@ fn parse_hexadecimal(s: &str) -> Result<PreciseNumber, ParseNumberError> {
     }
 }
 
+/// Parse a C99 hex floating-point literal, i.e. a hexadecimal mantissa
+/// (with an optional fractional part) and an optional binary exponent
+/// introduced by `p`/`P`, e.g. `1.8p3` means `1.8₁₆ × 2³ = 12`.
+///
+/// `s` is the part after the leading `0x`/`0X`, with any sign already
+/// stripped by [`parse_hexadecimal`]. The exponent defaults to `0` when
+/// absent, matching glibc's `strtod`.
+///
+/// # Errors
+///
+/// This function returns an error if the mantissa has no hex digits, or
+/// if the exponent (when present) is not a valid decimal integer.
+fn parse_hexadecimal_float(is_neg: bool, s: &str) -> Result<PreciseNumber, ParseNumberError> {
+    let (mantissa, exponent) = match s.to_lowercase().find('p') {
+        Some(i) => (
+            &s[..i],
+            s[i + 1..]
+                .parse::<i64>()
+                .map_err(|_| ParseNumberError::Hex)?,
+        ),
+        None => (s, 0i64),
+    };
+    let (int_part, frac_part) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(ParseNumberError::Hex);
+    }
+    let digits = if int_part.is_empty() && frac_part.is_empty() {
+        BigInt::zero()
+    } else {
+        BigInt::from_str_radix(&format!("{int_part}{frac_part}"), 16)
+            .map_err(|_| ParseNumberError::Hex)?
+    };
+
+    // Each hex digit is worth 4 bits, so shifting the point past
+    // `frac_part.len()` hex digits is a shift of `4 * frac_part.len()`
+    // bits, which we fold into the binary exponent.
+    let total_exp = exponent - 4 * frac_part.len() as i64;
+    let magnitude = if total_exp >= 0 {
+        BigDecimal::from(digits * BigInt::from(2).pow(total_exp as u32))
+    } else {
+        // digits / 2^k == (digits * 5^k) / 10^k, which BigDecimal can
+        // represent exactly as a fixed-scale integer.
+        let k = (-total_exp) as u32;
+        BigDecimal::new(digits * BigInt::from(5).pow(k), i64::from(k))
+    }
+    .normalized();
+
+    if is_neg && magnitude == BigDecimal::zero() {
+        return Ok(PreciseNumber::new(ExtendedBigDecimal::MinusZero, 2, 0));
+    }
+
+    // Derive the digit counts used to size the default rendering directly
+    // from `magnitude`'s (digits, scale) representation instead of
+    // rendering it to a string and re-scanning for the decimal point: a
+    // binary exponent like `p10000` can blow the resulting magnitude up
+    // to thousands of decimal digits, so re-parsing a rendered string
+    // just to count them back is wasted work.
+    let (num_integral_digits, num_fractional_digits) = decimal_digit_counts(&magnitude, is_neg);
+    let magnitude = if is_neg { -magnitude } else { magnitude };
+
+    Ok(PreciseNumber::new(
+        ExtendedBigDecimal::BigDecimal(magnitude),
+        num_integral_digits,
+        num_fractional_digits,
+    ))
+}
+
+/// Compute the number of integral and fractional digits that would be
+/// used to render `magnitude` (assumed non-negative), matching the
+/// convention used elsewhere in this module: `num_integral_digits`
+/// includes a leading `-` if `is_neg`, and always counts at least one
+/// integral digit (the `0` in `0.001`).
+fn decimal_digit_counts(magnitude: &BigDecimal, is_neg: bool) -> (usize, usize) {
+    let (digits, scale) = magnitude.as_bigint_and_exponent();
+    let sign_width = usize::from(is_neg);
+    let num_digits = digits.magnitude().to_string().len();
+    if scale <= 0 {
+        // A plain integer, possibly with trailing zeros contributed by a
+        // negative scale (e.g. digits = 12, scale = -1 means `120`).
+        (num_digits + sign_width + (-scale) as usize, 0)
+    } else {
+        let scale = scale as usize;
+        let num_fractional_digits = scale.min(MAX_FRACTIONAL_DIGITS);
+        // If there are more fractional digits than there are digits in
+        // total, the integral part is just "0" (e.g. `0.001`).
+        let num_integral_digits = num_digits.saturating_sub(scale).max(1);
+        (num_integral_digits + sign_width, num_fractional_digits)
+    }
+}
+
 impl FromStr for PreciseNumber {
     type Err = ParseNumberError;
     fn from_str(mut s: &str) -> Result<Self, Self::Err> {
@@ -396,6 +532,51 @@ mod tests {
         );
     }
 
+    /// C99 hex floats: a hex mantissa (with an optional fractional
+    /// part) and an optional binary exponent introduced by `p`.
+    #[test]
+    fn test_parse_hexadecimal_float() {
+        assert_eq!(
+            parse("0x1.8p3"),
+            ExtendedBigDecimal::BigDecimal("12".parse::<BigDecimal>().unwrap())
+        );
+        assert_eq!(
+            parse("-0x1.8p3"),
+            ExtendedBigDecimal::BigDecimal("-12".parse::<BigDecimal>().unwrap())
+        );
+        // No exponent: defaults to `p0`, matching glibc's `strtod`.
+        assert_eq!(
+            parse("0x1.8"),
+            ExtendedBigDecimal::BigDecimal("1.5".parse::<BigDecimal>().unwrap())
+        );
+        // A negative binary exponent still divides out exactly, since
+        // 1 / 2^n always terminates in decimal.
+        assert_eq!(
+            parse("0x1p-2"),
+            ExtendedBigDecimal::BigDecimal("0.25".parse::<BigDecimal>().unwrap())
+        );
+        assert_eq!(parse("-0x0.0p0"), ExtendedBigDecimal::MinusZero);
+    }
+
+    #[test]
+    fn test_parse_hexadecimal_float_digit_counts() {
+        assert_eq!(num_integral_digits("0x1.8p3"), 2);
+        assert_eq!(num_fractional_digits("0x1.8p3"), 0);
+        assert_eq!(num_integral_digits("0x1.8"), 1);
+        assert_eq!(num_fractional_digits("0x1.8"), 1);
+    }
+
+    /// A binary exponent this large produces a magnitude with thousands of
+    /// decimal digits; the digit counts must come from the exponent/scale
+    /// directly rather than from rendering that magnitude to a string.
+    #[test]
+    fn test_parse_hexadecimal_float_digit_counts_large_exponent() {
+        assert_eq!(num_integral_digits("0x1.8p10000"), 3011);
+        assert_eq!(num_fractional_digits("0x1.8p10000"), 0);
+        assert_eq!(num_integral_digits("-0x1.8p10000"), 3012);
+        assert_eq!(num_fractional_digits("0x1p-10000"), 10000);
+    }
+
     #[test]
     fn test_parse_big_decimal() {
         assert_eq!(
@@ -430,6 +611,95 @@ mod tests {
         assert_eq!(parse("-infinity"), ExtendedBigDecimal::MinusInfinity);
     }
 
+    #[test]
+    fn test_parse_leading_plus_sign() {
+        assert_eq!(
+            parse("+5"),
+            ExtendedBigDecimal::BigDecimal("5".parse().unwrap())
+        );
+        assert_eq!(
+            parse("+0.5"),
+            ExtendedBigDecimal::BigDecimal("0.5".parse().unwrap())
+        );
+        assert_eq!(
+            parse("+1e3"),
+            ExtendedBigDecimal::BigDecimal("1000".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_lone_plus_sign_is_error() {
+        assert_eq!(
+            "+".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Float
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_and_whitespace_is_error() {
+        assert_eq!(
+            "".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Float
+        );
+        assert_eq!(
+            "   ".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Float
+        );
+    }
+
+    #[test]
+    fn test_parse_inf_mixed_case() {
+        assert_eq!(parse("INF"), ExtendedBigDecimal::Infinity);
+        assert_eq!(parse("Inf"), ExtendedBigDecimal::Infinity);
+        assert_eq!(parse("INFINITY"), ExtendedBigDecimal::Infinity);
+        assert_eq!(parse("Infinity"), ExtendedBigDecimal::Infinity);
+        assert_eq!(parse("+INF"), ExtendedBigDecimal::Infinity);
+        assert_eq!(parse("-INF"), ExtendedBigDecimal::MinusInfinity);
+        assert_eq!(parse("-Infinity"), ExtendedBigDecimal::MinusInfinity);
+        assert_eq!(parse("-INFINITY"), ExtendedBigDecimal::MinusInfinity);
+    }
+
+    #[test]
+    fn test_parse_nan_mixed_case_is_error() {
+        assert_eq!(
+            "NAN".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Nan
+        );
+        assert_eq!(
+            "-NAN".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Nan
+        );
+        assert_eq!(
+            "Nan".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Nan
+        );
+    }
+
+    #[test]
+    fn test_parse_nan_n_char_sequence() {
+        assert_eq!(
+            "nan(123)".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Nan
+        );
+        assert_eq!(
+            "nan()".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Nan
+        );
+        assert_eq!(
+            "-nan(abc)".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Nan
+        );
+        assert_eq!(
+            "NAN(ABC)".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Nan
+        );
+        // Missing the parentheses entirely is not a NaN literal.
+        assert_eq!(
+            "nanabc".parse::<PreciseNumber>().unwrap_err(),
+            ParseNumberError::Float
+        );
+    }
+
     #[test]
     fn test_parse_invalid_float() {
         assert_eq!(
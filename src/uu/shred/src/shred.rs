@@ -8,7 +8,7 @@
 use clap::{crate_version, Arg, ArgAction, Command};
 #[cfg(unix)]
 use libc::S_IWUSR;
-use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use rand::{rngs::StdRng, seq::SliceRandom, RngCore, SeedableRng};
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, Seek, Write};
 #[cfg(unix)]
@@ -17,6 +17,7 @@ use std::path::{Path, PathBuf};
 use uucore::display::Quotable;
 use uucore::error::{FromIo, UResult, USimpleError, UUsageError};
 use uucore::parse_size::parse_size_u64;
+use uucore::rand_read_adapter;
 use uucore::{format_usage, help_about, help_section, help_usage, show_error, show_if_err};
 
 const ABOUT: &str = help_about!("shred.md");
@@ -33,6 +34,7 @@ pub mod options {
     pub const VERBOSE: &str = "verbose";
     pub const EXACT: &str = "exact";
     pub const ZERO: &str = "zero";
+    pub const RANDOM_SOURCE: &str = "random-source";
 
     pub mod remove {
         pub const UNLINK: &str = "unlink";
@@ -146,14 +148,70 @@ impl Iterator for FilenameIter {
     }
 }
 
+/// A source of randomness for the `Random` pass type.
+///
+/// By default this draws from the system entropy source via [`StdRng`], but
+/// `--random-source=FILE` lets the bytes be read from an arbitrary file
+/// instead (useful for reproducible testing).
+// The lint warns about the size difference between variants, but ShredRng
+// is short-lived and only one instance exists per run, so it's fine.
+#[allow(clippy::large_enum_variant)]
+enum ShredRng {
+    Entropy(StdRng),
+    File(rand_read_adapter::ReadRng<File>),
+}
+
+impl ShredRng {
+    fn new(random_source: Option<&str>) -> UResult<Self> {
+        match random_source {
+            Some(path) => {
+                let file = File::open(path)
+                    .map_err_context(|| format!("failed to open random source {}", path.quote()))?;
+                Ok(Self::File(rand_read_adapter::ReadRng::new(file)))
+            }
+            None => Ok(Self::Entropy(StdRng::from_entropy())),
+        }
+    }
+}
+
+impl RngCore for ShredRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Self::Entropy(rng) => rng.next_u32(),
+            Self::File(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Self::Entropy(rng) => rng.next_u64(),
+            Self::File(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Self::Entropy(rng) => rng.fill_bytes(dest),
+            Self::File(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match self {
+            Self::Entropy(rng) => rng.try_fill_bytes(dest),
+            Self::File(rng) => rng.try_fill_bytes(dest),
+        }
+    }
+}
+
 /// Used to generate blocks of bytes of size <= BLOCK_SIZE based on either a give pattern
 /// or randomness
-// The lint warns about a large difference because StdRng is big, but the buffers are much
+// The lint warns about a large difference because the buffers are much
 // larger anyway, so it's fine.
 #[allow(clippy::large_enum_variant)]
-enum BytesWriter {
+enum BytesWriter<'a> {
     Random {
-        rng: StdRng,
+        rng: &'a mut ShredRng,
         buffer: [u8; BLOCK_SIZE],
     },
     // To write patterns we only write to the buffer once. To be able to do
@@ -171,11 +229,11 @@ enum BytesWriter {
     },
 }
 
-impl BytesWriter {
-    fn from_pass_type(pass: &PassType) -> Self {
+impl<'a> BytesWriter<'a> {
+    fn from_pass_type(pass: &PassType, rng: &'a mut ShredRng) -> Self {
         match pass {
             PassType::Random => Self::Random {
-                rng: StdRng::from_entropy(),
+                rng,
                 buffer: [0; BLOCK_SIZE],
             },
             PassType::Pattern(pattern) => {
@@ -197,17 +255,18 @@ impl BytesWriter {
         }
     }
 
-    fn bytes_for_pass(&mut self, size: usize) -> &[u8] {
+    fn bytes_for_pass(&mut self, size: usize) -> io::Result<&[u8]> {
         match self {
             Self::Random { rng, buffer } => {
                 let bytes = &mut buffer[..size];
-                rng.fill(bytes);
-                bytes
+                rng.try_fill_bytes(bytes)
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                Ok(bytes)
             }
             Self::Pattern { offset, buffer } => {
                 let bytes = &buffer[*offset..size + *offset];
                 *offset = (*offset + size) % PATTERN_LENGTH;
-                bytes
+                Ok(bytes)
             }
         }
     }
@@ -234,7 +293,10 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         None => unreachable!(),
     };
 
-    // TODO: implement --random-source
+    let random_source = matches
+        .get_one::<String>(options::RANDOM_SOURCE)
+        .map(|s| s.as_str());
+    let mut rng = ShredRng::new(random_source)?;
 
     let remove_method = if matches.get_flag(options::WIPESYNC) {
         RemoveMethod::WipeSync
@@ -271,6 +333,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             zero,
             verbose,
             force,
+            &mut rng,
         ));
     }
     Ok(())
@@ -305,6 +368,13 @@ pub fn uu_app() -> Command {
                 .value_name("N")
                 .help("shred this many bytes (suffixes like K, M, G accepted)"),
         )
+        .arg(
+            Arg::new(options::RANDOM_SOURCE)
+                .long(options::RANDOM_SOURCE)
+                .value_name("FILE")
+                .help("get random bytes from FILE")
+                .value_hint(clap::ValueHint::FilePath),
+        )
         .arg(
             Arg::new(options::WIPESYNC)
                 .short('u')
@@ -391,6 +461,7 @@ fn wipe_file(
     zero: bool,
     verbose: bool,
     force: bool,
+    rng: &mut ShredRng,
 ) -> UResult<()> {
     // Get these potential errors out of the way first
     let path = Path::new(path_str);
@@ -492,7 +563,7 @@ fn wipe_file(
         }
         // size is an optional argument for exactly how many bytes we want to shred
         // Ignore failed writes; just keep trying
-        show_if_err!(do_pass(&mut file, &pass_type, exact, size)
+        show_if_err!(do_pass(&mut file, &pass_type, exact, size, rng)
             .map_err_context(|| format!("{}: File write pass failed", path.maybe_quote())));
     }
 
@@ -508,15 +579,16 @@ fn do_pass(
     pass_type: &PassType,
     exact: bool,
     file_size: u64,
+    rng: &mut ShredRng,
 ) -> Result<(), io::Error> {
     // We might be at the end of the file due to a previous iteration, so rewind.
     file.rewind()?;
 
-    let mut writer = BytesWriter::from_pass_type(pass_type);
+    let mut writer = BytesWriter::from_pass_type(pass_type, rng);
 
     // We start by writing BLOCK_SIZE times as many time as possible.
     for _ in 0..(file_size / BLOCK_SIZE as u64) {
-        let block = writer.bytes_for_pass(BLOCK_SIZE);
+        let block = writer.bytes_for_pass(BLOCK_SIZE)?;
         file.write_all(block)?;
     }
 
@@ -525,7 +597,7 @@ fn do_pass(
     let bytes_left = (file_size % BLOCK_SIZE as u64) as usize;
     if bytes_left > 0 {
         let size = if exact { bytes_left } else { BLOCK_SIZE };
-        let block = writer.bytes_for_pass(size);
+        let block = writer.bytes_for_pass(size)?;
         file.write_all(block)?;
     }
 
@@ -11,14 +11,13 @@ use rand::prelude::SliceRandom;
 use rand::{Rng, RngCore};
 use std::collections::HashSet;
 use std::fs::File;
-use std::io::{stdin, stdout, BufReader, BufWriter, Error, Read, Write};
+use std::io::{stdin, stdout, BufRead, BufReader, BufWriter, Error, Read, Write};
 use std::ops::RangeInclusive;
 use uucore::display::Quotable;
 use uucore::error::{FromIo, UResult, USimpleError, UUsageError};
+use uucore::rand_read_adapter;
 use uucore::{format_usage, help_about, help_usage};
 
-mod rand_read_adapter;
-
 enum Mode {
     Default(String),
     Echo(Vec<String>),
@@ -124,10 +123,17 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             shuf_exec(&mut range, options)?;
         }
         Mode::Default(filename) => {
-            let fdata = read_input_file(&filename)?;
-            let mut fdata = vec![&fdata[..]];
-            find_seps(&mut fdata, options.sep);
-            shuf_exec(&mut fdata, options)?;
+            // With -n but no -r, the output is a bounded-size random sample of
+            // the input, so we can stream it through reservoir sampling
+            // instead of buffering the (possibly huge) whole input in memory.
+            if options.head_count != std::usize::MAX && !options.repeat {
+                shuf_exec_streaming(&filename, options)?;
+            } else {
+                let fdata = read_input_file(&filename)?;
+                let mut fdata = vec![&fdata[..]];
+                find_seps(&mut fdata, options.sep);
+                shuf_exec(&mut fdata, options)?;
+            }
         }
     }
 
@@ -203,14 +209,18 @@ pub fn uu_app() -> Command {
         )
 }
 
-fn read_input_file(filename: &str) -> UResult<Vec<u8>> {
-    let mut file = BufReader::new(if filename == "-" {
+fn open_input(filename: &str) -> UResult<BufReader<Box<dyn Read>>> {
+    Ok(BufReader::new(if filename == "-" {
         Box::new(stdin()) as Box<dyn Read>
     } else {
         let file = File::open(filename)
             .map_err_context(|| format!("failed to open {}", filename.quote()))?;
         Box::new(file) as Box<dyn Read>
-    });
+    }))
+}
+
+fn read_input_file(filename: &str) -> UResult<Vec<u8>> {
+    let mut file = open_input(filename)?;
 
     let mut data = Vec::new();
     file.read_to_end(&mut data)
@@ -417,24 +427,92 @@ impl Writable for usize {
     }
 }
 
-fn shuf_exec(input: &mut impl Shufable, opts: Options) -> UResult<()> {
-    let mut output = BufWriter::new(match opts.output {
+fn open_output(output: &Option<String>) -> UResult<Box<dyn Write>> {
+    Ok(match output {
         None => Box::new(stdout()) as Box<dyn Write>,
         Some(s) => {
             let file = File::create(&s[..])
                 .map_err_context(|| format!("failed to open {} for writing", s.quote()))?;
             Box::new(file) as Box<dyn Write>
         }
-    });
+    })
+}
 
-    let mut rng = match opts.random_source {
+fn make_rng(random_source: &Option<String>) -> UResult<WrappedRng> {
+    Ok(match random_source {
         Some(r) => {
             let file = File::open(&r[..])
                 .map_err_context(|| format!("failed to open random source {}", r.quote()))?;
             WrappedRng::RngFile(rand_read_adapter::ReadRng::new(file))
         }
         None => WrappedRng::RngDefault(rand::thread_rng()),
-    };
+    })
+}
+
+/// Reservoir-sample (Algorithm R) up to `amount` records out of `input`,
+/// without ever holding more than `amount` of them in memory at once.
+///
+/// This is what lets `shuf -n K` handle stdin or huge files: the input is
+/// read once, record by record, and only the reservoir itself (plus the
+/// current record) needs to be kept around. The returned records are in
+/// input order; the caller is responsible for shuffling them, since
+/// reservoir sampling alone does not produce a uniformly random order.
+fn reservoir_sample_lines(
+    mut input: impl BufRead,
+    sep: u8,
+    amount: usize,
+    rng: &mut WrappedRng,
+) -> UResult<Vec<Vec<u8>>> {
+    let mut reservoir: Vec<Vec<u8>> = Vec::with_capacity(amount.min(1024));
+    let mut seen: usize = 0;
+    let mut record = Vec::new();
+    loop {
+        record.clear();
+        let n = input
+            .read_until(sep, &mut record)
+            .map_err_context(|| "failed reading input".to_string())?;
+        if n == 0 {
+            break;
+        }
+        if record.last() == Some(&sep) {
+            record.pop();
+        }
+        if reservoir.len() < amount {
+            reservoir.push(std::mem::take(&mut record));
+        } else if amount > 0 {
+            let j = rng.gen_range(0..=seen);
+            if j < amount {
+                reservoir[j] = std::mem::take(&mut record);
+            }
+        }
+        seen += 1;
+    }
+    Ok(reservoir)
+}
+
+fn shuf_exec_streaming(filename: &str, opts: Options) -> UResult<()> {
+    let input = open_input(filename)?;
+    let mut output = BufWriter::new(open_output(&opts.output)?);
+    let mut rng = make_rng(&opts.random_source)?;
+
+    let mut reservoir = reservoir_sample_lines(input, opts.sep, opts.head_count, &mut rng)?;
+    reservoir.shuffle(&mut rng);
+
+    for record in &reservoir {
+        output
+            .write_all(record)
+            .map_err_context(|| "write failed".to_string())?;
+        output
+            .write_all(&[opts.sep])
+            .map_err_context(|| "write failed".to_string())?;
+    }
+
+    Ok(())
+}
+
+fn shuf_exec(input: &mut impl Shufable, opts: Options) -> UResult<()> {
+    let mut output = BufWriter::new(open_output(&opts.output)?);
+    let mut rng = make_rng(&opts.random_source)?;
 
     if opts.repeat {
         if input.is_empty() {
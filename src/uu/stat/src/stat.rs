@@ -749,6 +749,17 @@ impl Stater {
                                     'd' => OutputType::Unsigned(meta.dev()),
                                     // device number in hex
                                     'D' => OutputType::UnsignedHex(meta.dev()),
+                                    // SELinux security context string
+                                    'C' => OutputType::Str(
+                                        uucore::selinux::get_security_context(
+                                            Path::new(&file),
+                                            self.follow,
+                                        )
+                                        .unwrap_or_default()
+                                        .unwrap_or_else(|| {
+                                            uucore::selinux::UNKNOWN_SECURITY_CONTEXT.to_string()
+                                        }),
+                                    ),
                                     // raw mode in hex
                                     'f' => OutputType::UnsignedHex(meta.mode() as u64),
                                     // file type
@@ -856,7 +867,8 @@ impl Stater {
     }
 
     fn default_format(show_fs: bool, terse: bool, show_dev_type: bool) -> String {
-        // SELinux related format is *ignored*
+        // The SELinux context (%C) isn't part of any default template; it's
+        // only shown when a user explicitly asks for it in a custom format.
 
         if show_fs {
             if terse {
@@ -35,6 +35,10 @@ mod platform {
     use std::fs::File;
     #[cfg(any(target_os = "linux", target_os = "android"))]
     use std::os::unix::io::AsRawFd;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    use uucore::display::Quotable;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    use uucore::error::{FromIo, UResult};
 
     pub unsafe fn do_sync() -> isize {
         // see https://github.com/rust-lang/libc/pull/2161
@@ -46,23 +50,31 @@ mod platform {
     }
 
     #[cfg(any(target_os = "linux", target_os = "android"))]
-    pub unsafe fn do_syncfs(files: Vec<String>) -> isize {
-        for path in files {
-            let f = File::open(path).unwrap();
+    pub fn do_syncfs(files: Vec<String>) -> UResult<()> {
+        for path in &files {
+            let f =
+                File::open(path).map_err_context(|| format!("error opening {}", path.quote()))?;
             let fd = f.as_raw_fd();
-            libc::syscall(libc::SYS_syncfs, fd);
+            if unsafe { libc::syscall(libc::SYS_syncfs, fd) } != 0 {
+                return Err(std::io::Error::last_os_error())
+                    .map_err_context(|| format!("error syncing file system for {}", path.quote()));
+            }
         }
-        0
+        Ok(())
     }
 
     #[cfg(any(target_os = "linux", target_os = "android"))]
-    pub unsafe fn do_fdatasync(files: Vec<String>) -> isize {
-        for path in files {
-            let f = File::open(path).unwrap();
+    pub fn do_fdatasync(files: Vec<String>) -> UResult<()> {
+        for path in &files {
+            let f =
+                File::open(path).map_err_context(|| format!("error opening {}", path.quote()))?;
             let fd = f.as_raw_fd();
-            libc::syscall(libc::SYS_fdatasync, fd);
+            if unsafe { libc::syscall(libc::SYS_fdatasync, fd) } != 0 {
+                return Err(std::io::Error::last_os_error())
+                    .map_err_context(|| format!("error syncing {}", path.quote()));
+            }
         }
-        0
+        Ok(())
     }
 }
 
@@ -190,10 +202,10 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     #[allow(clippy::if_same_then_else)]
     if matches.get_flag(options::FILE_SYSTEM) {
         #[cfg(any(target_os = "linux", target_os = "android", target_os = "windows"))]
-        syncfs(files);
+        syncfs(files)?;
     } else if matches.get_flag(options::DATA) {
         #[cfg(any(target_os = "linux", target_os = "android"))]
-        fdatasync(files);
+        fdatasync(files)?;
     } else {
         sync();
     }
@@ -234,11 +246,17 @@ fn sync() -> isize {
 }
 
 #[cfg(any(target_os = "linux", target_os = "android", target_os = "windows"))]
-fn syncfs(files: Vec<String>) -> isize {
-    unsafe { platform::do_syncfs(files) }
+fn syncfs(files: Vec<String>) -> UResult<()> {
+    #[cfg(target_os = "windows")]
+    {
+        unsafe { platform::do_syncfs(files) };
+        Ok(())
+    }
+    #[cfg(not(target_os = "windows"))]
+    platform::do_syncfs(files)
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
-fn fdatasync(files: Vec<String>) -> isize {
-    unsafe { platform::do_fdatasync(files) }
+fn fdatasync(files: Vec<String>) -> UResult<()> {
+    platform::do_fdatasync(files)
 }
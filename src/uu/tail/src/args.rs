@@ -118,7 +118,15 @@ impl Default for FilterMode {
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum FollowMode {
+    /// `--follow=descriptor` (`-f`): keep following the same file descriptor,
+    /// even across a rename, so a `logrotate`-style `mv` still gets followed
+    /// under its old name.
     Descriptor,
+    /// `--follow=name`: keep following whatever file currently has the given
+    /// name. If it's renamed or removed and a new file appears under that
+    /// name (as `logrotate` does), GNU tail prints "has become
+    /// inaccessible"/"has appeared" and switches to the new file; combined
+    /// with `--retry`/`-F` this also covers a name that doesn't exist yet.
     Name,
 }
 
@@ -678,4 +686,14 @@ mod tests {
         assert_eq!(settings.follow, expected_follow_mode);
         assert_eq!(settings.retry, expected_retry);
     }
+
+    #[rstest]
+    #[case::default(vec!["-f"], false)]
+    #[case::disable_inotify(vec!["-f", "---disable-inotify"], true)]
+    #[case::use_polling(vec!["-f", "--use-polling"], true)]
+    fn test_parse_settings_use_polling(#[case] args: Vec<&str>, #[case] expected_use_polling: bool) {
+        let settings =
+            Settings::from(&uu_app().no_binary_name(true).get_matches_from(args)).unwrap();
+        assert_eq!(settings.use_polling, expected_use_polling);
+    }
 }
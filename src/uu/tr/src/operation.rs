@@ -7,7 +7,7 @@
 
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take},
+    bytes::complete::tag,
     character::complete::{digit1, one_of},
     combinator::{map, map_opt, peek, recognize, value},
     multi::{many0, many_m_n},
@@ -24,6 +24,122 @@ use uucore::error::UError;
 
 use crate::unicode_table;
 
+/// Codepoints in this private-use range are never produced by decoding valid
+/// UTF-8; we use them to tag a byte that didn't belong to any valid UTF-8
+/// sequence, so it can still flow through SET1/SET2 matching as a single
+/// symbol and be written back out unchanged (rather than being replaced by
+/// U+FFFD, which would change the byte length of the output).
+const INVALID_BYTE_TAG_BASE: u32 = 0xF780;
+
+/// Wraps a byte that isn't part of a valid UTF-8 sequence into a private-use
+/// `char` so it round-trips through the rest of the pipeline as one symbol.
+fn byte_to_char(b: u8) -> char {
+    char::from_u32(INVALID_BYTE_TAG_BASE + u32::from(b)).unwrap()
+}
+
+/// The inverse of [`byte_to_char`]: if `c` was produced by it, returns the
+/// original byte.
+fn char_as_invalid_byte(c: char) -> Option<u8> {
+    let v = u32::from(c);
+    (INVALID_BYTE_TAG_BASE..=INVALID_BYTE_TAG_BASE + 0xFF)
+        .contains(&v)
+        .then(|| (v - INVALID_BYTE_TAG_BASE) as u8)
+}
+
+/// Interprets a parsed octal/numeric byte value (0-255) the way GNU `tr`
+/// does: values below 128 name an ASCII character, values 128 and above
+/// name a raw byte (which will only ever match an invalid/standalone byte
+/// in multibyte input, never a byte that's part of a valid UTF-8 sequence).
+fn ascii_or_byte_to_char(b: u8) -> char {
+    if b < 128 {
+        b as char
+    } else {
+        byte_to_char(b)
+    }
+}
+
+/// Returns the raw byte values (0-255) that were named directly through an
+/// octal/numeric escape of 128 or above (e.g. `\200`, the ends of a
+/// `\200-\377` range) in any of `sets`. GNU `tr` matches these against the
+/// underlying bytes of the input verbatim, including bytes that are part of
+/// an otherwise-valid multibyte UTF-8 sequence, rather than against decoded
+/// codepoints; [`decode_line`] uses this to know which multibyte sequences
+/// it must still split into raw bytes for matching purposes.
+pub fn forced_raw_bytes(sets: &[&[char]]) -> HashSet<u8> {
+    sets.iter()
+        .flat_map(|set| set.iter())
+        .filter_map(|&c| char_as_invalid_byte(c))
+        .collect()
+}
+
+/// Pushes the characters of `s` (known-valid UTF-8) onto `out`, splitting
+/// any character whose encoded bytes overlap `forced_raw_bytes` into its
+/// individual raw bytes (see [`byte_to_char`]) instead of pushing it as a
+/// single codepoint. This keeps octal/byte-range SET members byte-oriented,
+/// the same idiom GNU `tr` relies on to strip 8-bit bytes (`tr -d
+/// '\200-\377'`) regardless of whether they happen to form valid UTF-8.
+fn push_utf8_chars(s: &str, forced_raw_bytes: &HashSet<u8>, out: &mut Vec<char>) {
+    if forced_raw_bytes.is_empty() {
+        out.extend(s.chars());
+        return;
+    }
+    let mut buf = [0u8; 4];
+    for c in s.chars() {
+        let encoded = c.encode_utf8(&mut buf).as_bytes();
+        if encoded.iter().any(|b| forced_raw_bytes.contains(b)) {
+            out.extend(encoded.iter().map(|&b| byte_to_char(b)));
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+/// Decodes one line of input into `char`s, matching the implied multibyte
+/// behavior of GNU `tr`: valid UTF-8 sequences decode to their codepoint,
+/// and any byte that doesn't form part of a valid sequence is passed
+/// through as its own symbol (see [`byte_to_char`]) so it survives
+/// untranslated instead of being mangled. `forced_raw_bytes` (see
+/// [`forced_raw_bytes`]) overrides this for bytes that a SET named
+/// directly via an octal/numeric escape, even inside an otherwise-valid
+/// sequence.
+fn decode_line(bytes: &[u8], forced_raw_bytes: &HashSet<u8>) -> Vec<char> {
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        match std::str::from_utf8(rest) {
+            Ok(valid) => {
+                push_utf8_chars(valid, forced_raw_bytes, &mut out);
+                break;
+            }
+            Err(e) => {
+                let valid_len = e.valid_up_to();
+                if valid_len > 0 {
+                    // SAFETY: `valid_up_to` bytes were just confirmed valid.
+                    push_utf8_chars(
+                        std::str::from_utf8(&rest[..valid_len]).unwrap(),
+                        forced_raw_bytes,
+                        &mut out,
+                    );
+                }
+                out.push(byte_to_char(rest[valid_len]));
+                rest = &rest[valid_len + 1..];
+            }
+        }
+    }
+    out
+}
+
+/// The inverse of [`decode_line`]: writes `c` back out as UTF-8, or as the
+/// original raw byte if it's an invalid-byte tag.
+fn encode_char(c: char, out: &mut Vec<u8>) {
+    if let Some(b) = char_as_invalid_byte(c) {
+        out.push(b);
+    } else {
+        let mut buf = [0u8; 4];
+        out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum BadSequence {
     MissingCharClassName,
@@ -62,10 +178,10 @@ impl UError for BadSequence {}
 
 #[derive(Debug, Clone, Copy)]
 pub enum Sequence {
-    Char(u8),
-    CharRange(u8, u8),
-    CharStar(u8),
-    CharRepeat(u8, usize),
+    Char(char),
+    CharRange(char, char),
+    CharStar(char),
+    CharRepeat(char, usize),
     Alnum,
     Alpha,
     Blank,
@@ -81,19 +197,26 @@ pub enum Sequence {
 }
 
 impl Sequence {
-    pub fn flatten(&self) -> Box<dyn Iterator<Item = u8>> {
+    pub fn flatten(&self) -> Box<dyn Iterator<Item = char>> {
         match self {
             Self::Char(c) => Box::new(std::iter::once(*c)),
-            Self::CharRange(l, r) => Box::new(*l..=*r),
+            Self::CharRange(l, r) => {
+                Box::new((u32::from(*l)..=u32::from(*r)).filter_map(char::from_u32))
+            }
             Self::CharStar(c) => Box::new(std::iter::repeat(*c)),
             Self::CharRepeat(c, n) => Box::new(std::iter::repeat(*c).take(*n)),
-            Self::Alnum => Box::new((b'0'..=b'9').chain(b'A'..=b'Z').chain(b'a'..=b'z')),
-            Self::Alpha => Box::new((b'A'..=b'Z').chain(b'a'..=b'z')),
-            Self::Blank => Box::new(unicode_table::BLANK.iter().cloned()),
-            Self::Control => Box::new((0..=31).chain(std::iter::once(127))),
-            Self::Digit => Box::new(b'0'..=b'9'),
+            Self::Alnum => Box::new(
+                (b'0'..=b'9')
+                    .chain(b'A'..=b'Z')
+                    .chain(b'a'..=b'z')
+                    .map(char::from),
+            ),
+            Self::Alpha => Box::new((b'A'..=b'Z').chain(b'a'..=b'z').map(char::from)),
+            Self::Blank => Box::new(unicode_table::BLANK.iter().map(|&b| char::from(b))),
+            Self::Control => Box::new((0..=31).chain(std::iter::once(127)).map(char::from)),
+            Self::Digit => Box::new((b'0'..=b'9').map(char::from)),
             Self::Graph => Box::new(
-                (48..=57) // digit
+                (48..=57u8) // digit
                     .chain(65..=90) // uppercase
                     .chain(97..=122) // lowercase
                     // punctuations
@@ -101,23 +224,36 @@ impl Sequence {
                     .chain(58..=64)
                     .chain(91..=96)
                     .chain(123..=126)
-                    .chain(std::iter::once(32)), // space
+                    .chain(std::iter::once(32)) // space
+                    .map(char::from),
             ),
-            Self::Lower => Box::new(b'a'..=b'z'),
+            Self::Lower => Box::new((b'a'..=b'z').map(char::from)),
             Self::Print => Box::new(
-                (48..=57) // digit
+                (48..=57u8) // digit
                     .chain(65..=90) // uppercase
                     .chain(97..=122) // lowercase
                     // punctuations
                     .chain(33..=47)
                     .chain(58..=64)
                     .chain(91..=96)
-                    .chain(123..=126),
+                    .chain(123..=126)
+                    .map(char::from),
+            ),
+            Self::Punct => Box::new(
+                (33..=47u8)
+                    .chain(58..=64)
+                    .chain(91..=96)
+                    .chain(123..=126)
+                    .map(char::from),
+            ),
+            Self::Space => Box::new(unicode_table::SPACES.iter().map(|&b| char::from(b))),
+            Self::Upper => Box::new((b'A'..=b'Z').map(char::from)),
+            Self::Xdigit => Box::new(
+                (b'0'..=b'9')
+                    .chain(b'A'..=b'F')
+                    .chain(b'a'..=b'f')
+                    .map(char::from),
             ),
-            Self::Punct => Box::new((33..=47).chain(58..=64).chain(91..=96).chain(123..=126)),
-            Self::Space => Box::new(unicode_table::SPACES.iter().cloned()),
-            Self::Upper => Box::new(b'A'..=b'Z'),
-            Self::Xdigit => Box::new((b'0'..=b'9').chain(b'A'..=b'F').chain(b'a'..=b'f')),
         }
     }
 
@@ -126,7 +262,7 @@ impl Sequence {
         set1_str: &[u8],
         set2_str: &[u8],
         truncate_set1_flag: bool,
-    ) -> Result<(Vec<u8>, Vec<u8>), BadSequence> {
+    ) -> Result<(Vec<char>, Vec<char>), BadSequence> {
         let set1 = Self::from_str(set1_str)?;
 
         let is_char_star = |s: &&Self| -> bool { matches!(s, Self::CharStar(_)) };
@@ -219,35 +355,59 @@ impl Sequence {
         .collect::<Result<Vec<_>, _>>()
     }
 
-    fn parse_octal(input: &[u8]) -> IResult<&[u8], u8> {
+    fn parse_octal(input: &[u8]) -> IResult<&[u8], char> {
         map_opt(
             preceded(tag("\\"), recognize(many_m_n(1, 3, one_of("01234567")))),
-            |out: &[u8]| u8::from_str_radix(std::str::from_utf8(out).expect("boop"), 8).ok(),
+            |out: &[u8]| {
+                u8::from_str_radix(std::str::from_utf8(out).expect("boop"), 8)
+                    .ok()
+                    .map(ascii_or_byte_to_char)
+            },
         )(input)
     }
 
-    fn parse_backslash(input: &[u8]) -> IResult<&[u8], u8> {
+    fn parse_backslash(input: &[u8]) -> IResult<&[u8], char> {
         preceded(tag("\\"), Self::single_char)(input).map(|(l, a)| {
             let c = match a {
-                b'a' => unicode_table::BEL,
-                b'b' => unicode_table::BS,
-                b'f' => unicode_table::FF,
-                b'n' => unicode_table::LF,
-                b'r' => unicode_table::CR,
-                b't' => unicode_table::HT,
-                b'v' => unicode_table::VT,
+                'a' => unicode_table::BEL as char,
+                'b' => unicode_table::BS as char,
+                'f' => unicode_table::FF as char,
+                'n' => unicode_table::LF as char,
+                'r' => unicode_table::CR as char,
+                't' => unicode_table::HT as char,
+                'v' => unicode_table::VT as char,
                 x => x,
             };
             (l, c)
         })
     }
 
-    fn parse_backslash_or_char(input: &[u8]) -> IResult<&[u8], u8> {
+    fn parse_backslash_or_char(input: &[u8]) -> IResult<&[u8], char> {
         alt((Self::parse_octal, Self::parse_backslash, Self::single_char))(input)
     }
 
-    fn single_char(input: &[u8]) -> IResult<&[u8], u8> {
-        take(1usize)(input).map(|(l, a)| (l, a[0]))
+    /// Consumes one logical character from `input`: a full UTF-8 codepoint
+    /// when one is present, or a single raw byte (tagged via
+    /// [`byte_to_char`]) when the next byte doesn't start a valid sequence.
+    fn single_char(input: &[u8]) -> IResult<&[u8], char> {
+        if input.is_empty() {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Eof,
+            )));
+        }
+        match std::str::from_utf8(input) {
+            Ok(s) => {
+                let c = s.chars().next().unwrap();
+                Ok((&input[c.len_utf8()..], c))
+            }
+            Err(e) if e.valid_up_to() > 0 => {
+                let s = std::str::from_utf8(&input[..e.valid_up_to()]).unwrap();
+                let c = s.chars().next().unwrap();
+                Ok((&input[c.len_utf8()..], c))
+            }
+            Err(_) => Ok((&input[1..], byte_to_char(input[0]))),
+        }
     }
 
     fn parse_char_range(input: &[u8]) -> IResult<&[u8], Result<Self, BadSequence>> {
@@ -256,12 +416,7 @@ impl Sequence {
             tag("-"),
             Self::parse_backslash_or_char,
         )(input)
-        .map(|(l, (a, b))| {
-            (l, {
-                let (start, end) = (u32::from(a), u32::from(b));
-                Ok(Self::CharRange(start as u8, end as u8))
-            })
-        })
+        .map(|(l, (a, b))| (l, Ok(Self::CharRange(a, b))))
     }
 
     fn parse_char_star(input: &[u8]) -> IResult<&[u8], Result<Self, BadSequence>> {
@@ -321,6 +476,14 @@ impl Sequence {
         )(input)
     }
 
+    /// Parses an equivalence class `[=c=]`.
+    ///
+    /// Without real locale collation data, a character's only equivalence
+    /// class member is itself (which is also what happens in the "C"
+    /// locale GNU `tr` runs under by default), so this is just a single
+    /// `Char`. It's handled as its own production (rather than falling
+    /// through to [`Self::parse_backslash_or_char`]) purely so `[==]`
+    /// reports the correct "missing equivalence class character" error.
     fn parse_char_equal(input: &[u8]) -> IResult<&[u8], Result<Self, BadSequence>> {
         delimited(
             tag("[="),
@@ -337,7 +500,7 @@ impl Sequence {
 }
 
 pub trait SymbolTranslator {
-    fn translate(&mut self, current: u8) -> Option<u8>;
+    fn translate(&mut self, current: char) -> Option<char>;
 
     /// Takes two SymbolTranslators and creates a new SymbolTranslator over both in sequence.
     ///
@@ -359,7 +522,7 @@ pub struct ChainedSymbolTranslator<A, B> {
 }
 
 impl<A: SymbolTranslator, B: SymbolTranslator> SymbolTranslator for ChainedSymbolTranslator<A, B> {
-    fn translate(&mut self, current: u8) -> Option<u8> {
+    fn translate(&mut self, current: char) -> Option<char> {
         self.stage_a
             .translate(current)
             .and_then(|c| self.stage_b.translate(c))
@@ -368,12 +531,12 @@ impl<A: SymbolTranslator, B: SymbolTranslator> SymbolTranslator for ChainedSymbo
 
 #[derive(Debug)]
 pub struct DeleteOperation {
-    set: Vec<u8>,
+    set: Vec<char>,
     complement_flag: bool,
 }
 
 impl DeleteOperation {
-    pub fn new(set: Vec<u8>, complement_flag: bool) -> Self {
+    pub fn new(set: Vec<char>, complement_flag: bool) -> Self {
         Self {
             set,
             complement_flag,
@@ -382,8 +545,8 @@ impl DeleteOperation {
 }
 
 impl SymbolTranslator for DeleteOperation {
-    fn translate(&mut self, current: u8) -> Option<u8> {
-        let found = self.set.iter().any(|sequence| *sequence == current);
+    fn translate(&mut self, current: char) -> Option<char> {
+        let found = self.set.contains(&current);
         if self.complement_flag == found {
             Some(current)
         } else {
@@ -393,15 +556,15 @@ impl SymbolTranslator for DeleteOperation {
 }
 
 pub struct TranslateOperationComplement {
-    iter: u8,
+    iter: u32,
     set2_iter: usize,
-    set1: Vec<u8>,
-    set2: Vec<u8>,
-    translation_map: HashMap<u8, u8>,
+    set1: Vec<char>,
+    set2: Vec<char>,
+    translation_map: HashMap<char, char>,
 }
 
 impl TranslateOperationComplement {
-    fn new(set1: Vec<u8>, set2: Vec<u8>) -> Self {
+    fn new(set1: Vec<char>, set2: Vec<char>) -> Self {
         Self {
             iter: 0,
             set2_iter: 0,
@@ -414,11 +577,11 @@ impl TranslateOperationComplement {
 
 #[derive(Debug)]
 pub struct TranslateOperationStandard {
-    translation_map: HashMap<u8, u8>,
+    translation_map: HashMap<char, char>,
 }
 
 impl TranslateOperationStandard {
-    fn new(set1: Vec<u8>, set2: Vec<u8>) -> Result<Self, BadSequence> {
+    fn new(set1: Vec<char>, set2: Vec<char>) -> Result<Self, BadSequence> {
         if let Some(fallback) = set2.last().copied() {
             Ok(Self {
                 translation_map: set1
@@ -442,17 +605,24 @@ pub enum TranslateOperation {
 }
 
 impl TranslateOperation {
-    fn next_complement_char(iter: u8, ignore_list: &[u8]) -> (u8, u8) {
-        (iter..)
-            .filter(|c| !ignore_list.iter().any(|s| s == c))
-            .map(|c| (c + 1, c))
-            .next()
-            .expect("exhausted all possible characters")
+    /// Finds the next codepoint at or after `start` that isn't in
+    /// `ignore_list`, skipping over the surrogate range (which isn't a
+    /// valid `char`).
+    fn next_complement_char(start: u32, ignore_list: &[char]) -> (u32, char) {
+        let mut next = start;
+        loop {
+            if let Some(c) = char::from_u32(next) {
+                if !ignore_list.contains(&c) {
+                    return (next + 1, c);
+                }
+            }
+            next += 1;
+        }
     }
 }
 
 impl TranslateOperation {
-    pub fn new(set1: Vec<u8>, set2: Vec<u8>, complement: bool) -> Result<Self, BadSequence> {
+    pub fn new(set1: Vec<char>, set2: Vec<char>, complement: bool) -> Result<Self, BadSequence> {
         if complement {
             Ok(Self::Complement(TranslateOperationComplement::new(
                 set1, set2,
@@ -464,14 +634,11 @@ impl TranslateOperation {
 }
 
 impl SymbolTranslator for TranslateOperation {
-    fn translate(&mut self, current: u8) -> Option<u8> {
+    fn translate(&mut self, current: char) -> Option<char> {
         match self {
-            Self::Standard(TranslateOperationStandard { translation_map }) => Some(
-                translation_map
-                    .iter()
-                    .find_map(|(l, r)| if l.eq(&current) { Some(*r) } else { None })
-                    .unwrap_or(current),
-            ),
+            Self::Standard(TranslateOperationStandard { translation_map }) => {
+                Some(translation_map.get(&current).copied().unwrap_or(current))
+            }
             Self::Complement(TranslateOperationComplement {
                 iter,
                 set2_iter,
@@ -506,13 +673,13 @@ impl SymbolTranslator for TranslateOperation {
 
 #[derive(Debug, Clone)]
 pub struct SqueezeOperation {
-    set1: HashSet<u8>,
+    set1: HashSet<char>,
     complement: bool,
-    previous: Option<u8>,
+    previous: Option<char>,
 }
 
 impl SqueezeOperation {
-    pub fn new(set1: Vec<u8>, complement: bool) -> Self {
+    pub fn new(set1: Vec<char>, complement: bool) -> Self {
         Self {
             set1: set1.into_iter().collect(),
             complement,
@@ -522,7 +689,7 @@ impl SqueezeOperation {
 }
 
 impl SymbolTranslator for SqueezeOperation {
-    fn translate(&mut self, current: u8) -> Option<u8> {
+    fn translate(&mut self, current: char) -> Option<char> {
         if self.complement {
             let next = if self.set1.contains(&current) {
                 Some(current)
@@ -555,8 +722,12 @@ impl SymbolTranslator for SqueezeOperation {
     }
 }
 
-pub fn translate_input<T, R, W>(input: &mut R, output: &mut W, mut translator: T)
-where
+pub fn translate_input<T, R, W>(
+    input: &mut R,
+    output: &mut W,
+    mut translator: T,
+    forced_raw_bytes: &HashSet<u8>,
+) where
     T: SymbolTranslator,
     R: BufRead,
     W: Write,
@@ -567,8 +738,11 @@ where
         if length == 0 {
             break;
         } else {
-            let filtered = buf.iter().filter_map(|c| translator.translate(*c));
-            output_buf.extend(filtered);
+            for c in decode_line(&buf, forced_raw_bytes) {
+                if let Some(c) = translator.translate(c) {
+                    encode_char(c, &mut output_buf);
+                }
+            }
             output.write_all(&output_buf).unwrap();
         }
         buf.clear();
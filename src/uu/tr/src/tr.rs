@@ -10,7 +10,8 @@ mod unicode_table;
 
 use clap::{crate_version, Arg, ArgAction, Command};
 use operation::{
-    translate_input, Sequence, SqueezeOperation, SymbolTranslator, TranslateOperation,
+    forced_raw_bytes, translate_input, Sequence, SqueezeOperation, SymbolTranslator,
+    TranslateOperation,
 };
 use std::io::{stdin, stdout, BufWriter};
 use uucore::{format_usage, help_about, help_section, help_usage, show};
@@ -117,6 +118,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         sets_iter.next().unwrap_or_default().as_bytes(),
         truncate_set1_flag,
     )?;
+    let forced_raw_bytes = forced_raw_bytes(&[&set1, &set2]);
 
     // '*_op' are the operations that need to be applied, in order.
     if delete_flag {
@@ -124,24 +126,49 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             let delete_op = DeleteOperation::new(set1, complement_flag);
             let squeeze_op = SqueezeOperation::new(set2, false);
             let op = delete_op.chain(squeeze_op);
-            translate_input(&mut locked_stdin, &mut buffered_stdout, op);
+            translate_input(
+                &mut locked_stdin,
+                &mut buffered_stdout,
+                op,
+                &forced_raw_bytes,
+            );
         } else {
             let op = DeleteOperation::new(set1, complement_flag);
-            translate_input(&mut locked_stdin, &mut buffered_stdout, op);
+            translate_input(
+                &mut locked_stdin,
+                &mut buffered_stdout,
+                op,
+                &forced_raw_bytes,
+            );
         }
     } else if squeeze_flag {
         if sets_len < 2 {
             let op = SqueezeOperation::new(set1, complement_flag);
-            translate_input(&mut locked_stdin, &mut buffered_stdout, op);
+            translate_input(
+                &mut locked_stdin,
+                &mut buffered_stdout,
+                op,
+                &forced_raw_bytes,
+            );
         } else {
             let translate_op = TranslateOperation::new(set1, set2.clone(), complement_flag)?;
             let squeeze_op = SqueezeOperation::new(set2, false);
             let op = translate_op.chain(squeeze_op);
-            translate_input(&mut locked_stdin, &mut buffered_stdout, op);
+            translate_input(
+                &mut locked_stdin,
+                &mut buffered_stdout,
+                op,
+                &forced_raw_bytes,
+            );
         }
     } else {
         let op = TranslateOperation::new(set1, set2, complement_flag)?;
-        translate_input(&mut locked_stdin, &mut buffered_stdout, op);
+        translate_input(
+            &mut locked_stdin,
+            &mut buffered_stdout,
+            op,
+            &forced_raw_bytes,
+        );
     }
     Ok(())
 }
@@ -8,7 +8,7 @@ use clap::{crate_version, Arg, ArgAction, Command};
 use std::fs::{metadata, OpenOptions};
 use std::io::ErrorKind;
 #[cfg(unix)]
-use std::os::unix::fs::FileTypeExt;
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
 use std::path::Path;
 use uucore::display::Quotable;
 use uucore::error::{FromIo, UResult, USimpleError, UUsageError};
@@ -29,7 +29,9 @@ enum TruncateMode {
 impl TruncateMode {
     /// Compute a target size in bytes for this truncate mode.
     ///
-    /// `fsize` is the size of the reference file, in bytes.
+    /// `fsize` is the size of the reference file, in bytes. `block_size`
+    /// scales the mode's own size value, which is 1 for plain byte sizes
+    /// and the file's preferred I/O block size when `--io-blocks` is given.
     ///
     /// If the mode is [`TruncateMode::Reduce`] and the value to
     /// reduce by is greater than `fsize`, then this function returns
@@ -42,7 +44,7 @@ impl TruncateMode {
     /// ```rust,ignore
     /// let mode = TruncateMode::Extend(5);
     /// let fsize = 10;
-    /// assert_eq!(mode.to_size(fsize), 15);
+    /// assert_eq!(mode.to_size(fsize, 1), 15);
     /// ```
     ///
     /// Reducing a file by more than its size results in 0:
@@ -50,23 +52,23 @@ impl TruncateMode {
     /// ```rust,ignore
     /// let mode = TruncateMode::Reduce(5);
     /// let fsize = 3;
-    /// assert_eq!(mode.to_size(fsize), 0);
+    /// assert_eq!(mode.to_size(fsize, 1), 0);
     /// ```
-    fn to_size(&self, fsize: u64) -> u64 {
+    fn to_size(&self, fsize: u64, block_size: u64) -> u64 {
         match self {
-            Self::Absolute(size) => *size,
-            Self::Extend(size) => fsize + size,
-            Self::Reduce(size) => {
-                if *size > fsize {
-                    0
-                } else {
-                    fsize - size
-                }
+            Self::Absolute(size) => size * block_size,
+            Self::Extend(size) => fsize + size * block_size,
+            Self::Reduce(size) => fsize.saturating_sub(size * block_size),
+            Self::AtMost(size) => fsize.min(size * block_size),
+            Self::AtLeast(size) => fsize.max(size * block_size),
+            Self::RoundDown(size) => {
+                let size = size * block_size;
+                fsize - fsize % size
+            }
+            Self::RoundUp(size) => {
+                let size = size * block_size;
+                fsize + fsize % size
             }
-            Self::AtMost(size) => fsize.min(*size),
-            Self::AtLeast(size) => fsize.max(*size),
-            Self::RoundDown(size) => fsize - fsize % size,
-            Self::RoundUp(size) => fsize + fsize % size,
         }
     }
 }
@@ -105,6 +107,13 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         Err(UUsageError::new(1, "missing file operand"))
     } else {
         let io_blocks = matches.get_flag(options::IO_BLOCKS);
+        #[cfg(not(unix))]
+        if io_blocks {
+            return Err(USimpleError::new(
+                1,
+                "--io-blocks is not supported on this platform",
+            ));
+        }
         let no_create = matches.get_flag(options::NO_CREATE);
         let reference = matches
             .get_one::<String>(options::REFERENCE)
@@ -124,10 +133,7 @@ pub fn uu_app() -> Command {
             Arg::new(options::IO_BLOCKS)
                 .short('o')
                 .long(options::IO_BLOCKS)
-                .help(
-                    "treat SIZE as the number of I/O blocks of the file rather than bytes \
-            (NOT IMPLEMENTED)",
-                )
+                .help("treat SIZE as the number of I/O blocks of the file rather than bytes")
                 .action(ArgAction::SetTrue),
         )
         .arg(
@@ -200,6 +206,28 @@ fn file_truncate(filename: &str, create: bool, size: u64) -> UResult<()> {
     .map_err_context(|| format!("cannot open {} for writing", filename.quote()))
 }
 
+/// Get the preferred I/O block size to use for `--io-blocks`, in bytes.
+///
+/// Returns 1 (so that `size * block_size == size`) when `io_blocks` is
+/// false. Otherwise stats `filename` for its `st_blksize`, falling back
+/// to 1 if the file does not exist (it will be created with a default
+/// block size once opened).
+#[cfg(unix)]
+fn block_size(filename: &str, io_blocks: bool) -> u64 {
+    if !io_blocks {
+        return 1;
+    }
+    metadata(filename)
+        .map(|m| m.blksize())
+        .unwrap_or(1)
+        .max(1)
+}
+
+#[cfg(not(unix))]
+fn block_size(_filename: &str, _io_blocks: bool) -> u64 {
+    1
+}
+
 /// Truncate files to a size relative to a given file.
 ///
 /// `rfilename` is the name of the reference file.
@@ -222,6 +250,7 @@ fn truncate_reference_and_size(
     size_string: &str,
     filenames: &[String],
     create: bool,
+    io_blocks: bool,
 ) -> UResult<()> {
     let mode = match parse_mode_and_size(size_string) {
         Err(e) => return Err(USimpleError::new(1, format!("Invalid number: {e}"))),
@@ -247,8 +276,8 @@ fn truncate_reference_and_size(
         _ => e.map_err_context(String::new),
     })?;
     let fsize = metadata.len();
-    let tsize = mode.to_size(fsize);
     for filename in filenames {
+        let tsize = mode.to_size(fsize, block_size(filename, io_blocks));
         file_truncate(filename, create, tsize)?;
     }
     Ok(())
@@ -306,7 +335,12 @@ fn truncate_reference_file_only(
 /// the size of at least one file.
 ///
 /// If at least one file is a named pipe (also known as a fifo).
-fn truncate_size_only(size_string: &str, filenames: &[String], create: bool) -> UResult<()> {
+fn truncate_size_only(
+    size_string: &str,
+    filenames: &[String],
+    create: bool,
+    io_blocks: bool,
+) -> UResult<()> {
     let mode = parse_mode_and_size(size_string)
         .map_err(|e| USimpleError::new(1, format!("Invalid number: {e}")))?;
     if let TruncateMode::RoundDown(0) | TruncateMode::RoundUp(0) = mode {
@@ -329,7 +363,7 @@ fn truncate_size_only(size_string: &str, filenames: &[String], create: bool) ->
             }
             Err(_) => 0,
         };
-        let tsize = mode.to_size(fsize);
+        let tsize = mode.to_size(fsize, block_size(filename, io_blocks));
         // TODO: Fix duplicate call to stat
         file_truncate(filename, create, tsize)?;
     }
@@ -338,7 +372,7 @@ fn truncate_size_only(size_string: &str, filenames: &[String], create: bool) ->
 
 fn truncate(
     no_create: bool,
-    _: bool,
+    io_blocks: bool,
     reference: Option<String>,
     size: Option<String>,
     filenames: &[String],
@@ -351,10 +385,12 @@ fn truncate(
     // - no reference file given and no size given,
     match (reference, size) {
         (Some(rfilename), Some(size_string)) => {
-            truncate_reference_and_size(&rfilename, &size_string, filenames, create)
+            truncate_reference_and_size(&rfilename, &size_string, filenames, create, io_blocks)
         }
         (Some(rfilename), None) => truncate_reference_file_only(&rfilename, filenames, create),
-        (None, Some(size_string)) => truncate_size_only(&size_string, filenames, create),
+        (None, Some(size_string)) => {
+            truncate_size_only(&size_string, filenames, create, io_blocks)
+        }
         (None, None) => unreachable!(), // this case cannot happen anymore because it's handled by clap
     }
 }
@@ -424,8 +460,15 @@ mod tests {
 
     #[test]
     fn test_to_size() {
-        assert_eq!(TruncateMode::Extend(5).to_size(10), 15);
-        assert_eq!(TruncateMode::Reduce(5).to_size(10), 5);
-        assert_eq!(TruncateMode::Reduce(5).to_size(3), 0);
+        assert_eq!(TruncateMode::Extend(5).to_size(10, 1), 15);
+        assert_eq!(TruncateMode::Reduce(5).to_size(10, 1), 5);
+        assert_eq!(TruncateMode::Reduce(5).to_size(3, 1), 0);
+    }
+
+    #[test]
+    fn test_to_size_io_blocks() {
+        assert_eq!(TruncateMode::Absolute(2).to_size(0, 512), 1024);
+        assert_eq!(TruncateMode::Extend(2).to_size(10, 512), 1034);
+        assert_eq!(TruncateMode::Reduce(1).to_size(1024, 512), 512);
     }
 }
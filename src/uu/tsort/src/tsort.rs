@@ -8,8 +8,8 @@ use std::fs::File;
 use std::io::{stdin, BufReader, Read};
 use std::path::Path;
 use uucore::display::Quotable;
-use uucore::error::{FromIo, UResult, USimpleError};
-use uucore::{format_usage, help_about, help_usage};
+use uucore::error::{set_exit_code, FromIo, UResult, USimpleError};
+use uucore::{format_usage, help_about, help_usage, show_error};
 
 const ABOUT: &str = help_about!("tsort.md");
 const USAGE: &str = help_usage!("tsort.md");
@@ -71,10 +71,11 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     g.run_tsort();
 
     if !g.is_acyclic() {
-        return Err(USimpleError::new(
-            1,
-            format!("{input}, input contains a loop:"),
-        ));
+        show_error!("{input}, input contains a loop:");
+        for node in g.nodes_in_cycles() {
+            show_error!("{node}");
+        }
+        set_exit_code(1);
     }
 
     for x in &g.result {
@@ -173,4 +174,36 @@ impl<'input> Graph<'input> {
     fn is_acyclic(&self) -> bool {
         self.out_edges.values().all(|edge| edge.is_empty())
     }
+
+    /// Return the nodes that lie on a cycle, in alphabetical order.
+    ///
+    /// Must be called after `run_tsort`, which clears the out-edges of
+    /// every node it manages to resolve; any node with out-edges
+    /// remaining can only be stuck because it sits on a cycle, so we just
+    /// need to check, for each of those, whether it can reach itself.
+    fn nodes_in_cycles(&self) -> Vec<&'input str> {
+        self.out_edges
+            .keys()
+            .copied()
+            .filter(|&n| self.can_reach(n, n))
+            .collect()
+    }
+
+    /// Return `true` if `target` can be reached from `start` by following
+    /// one or more outgoing edges.
+    fn can_reach(&self, start: &'input str, target: &'input str) -> bool {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![start];
+        while let Some(n) = stack.pop() {
+            for &m in &self.out_edges[n] {
+                if m == target {
+                    return true;
+                }
+                if visited.insert(m) {
+                    stack.push(m);
+                }
+            }
+        }
+        false
+    }
 }
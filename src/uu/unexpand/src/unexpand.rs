@@ -6,16 +6,13 @@
 // spell-checker:ignore (ToDO) nums aflag uflag scol prevtab amode ctype cwidth nbytes lastcol pctype Preprocess
 
 use clap::{crate_version, Arg, ArgAction, Command};
-use std::error::Error;
-use std::fmt;
 use std::fs::File;
 use std::io::{stdin, stdout, BufRead, BufReader, BufWriter, Read, Stdout, Write};
-use std::num::IntErrorKind;
 use std::path::Path;
 use std::str::from_utf8;
 use unicode_width::UnicodeWidthChar;
-use uucore::display::Quotable;
-use uucore::error::{FromIo, UError, UResult, USimpleError};
+use uucore::error::{FromIo, UResult, USimpleError};
+use uucore::tabstops::{self, parse_tabstops as tabstops_parse, RemainingMode};
 use uucore::{crash_if_err, format_usage, help_about, help_usage, show};
 
 const USAGE: &str = help_usage!("unexpand.md");
@@ -23,63 +20,6 @@ const ABOUT: &str = help_about!("unexpand.md");
 
 const DEFAULT_TABSTOP: usize = 8;
 
-#[derive(Debug)]
-enum ParseError {
-    InvalidCharacter(String),
-    TabSizeCannotBeZero,
-    TabSizeTooLarge,
-    TabSizesMustBeAscending,
-}
-
-impl Error for ParseError {}
-impl UError for ParseError {}
-
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::InvalidCharacter(s) => {
-                write!(f, "tab size contains invalid character(s): {}", s.quote())
-            }
-            Self::TabSizeCannotBeZero => write!(f, "tab size cannot be 0"),
-            Self::TabSizeTooLarge => write!(f, "tab stop value is too large"),
-            Self::TabSizesMustBeAscending => write!(f, "tab sizes must be ascending"),
-        }
-    }
-}
-
-fn tabstops_parse(s: &str) -> Result<Vec<usize>, ParseError> {
-    let words = s.split(',');
-
-    let mut nums = Vec::new();
-
-    for word in words {
-        match word.parse::<usize>() {
-            Ok(num) => nums.push(num),
-            Err(e) => match e.kind() {
-                IntErrorKind::PosOverflow => return Err(ParseError::TabSizeTooLarge),
-                _ => {
-                    return Err(ParseError::InvalidCharacter(
-                        word.trim_start_matches(char::is_numeric).to_string(),
-                    ))
-                }
-            },
-        }
-    }
-
-    if nums.iter().any(|&n| n == 0) {
-        return Err(ParseError::TabSizeCannotBeZero);
-    }
-
-    if let (false, _) = nums
-        .iter()
-        .fold((true, 0), |(acc, last), &n| (acc && last < n, n))
-    {
-        return Err(ParseError::TabSizesMustBeAscending);
-    }
-
-    Ok(nums)
-}
-
 mod options {
     pub const FILE: &str = "file";
     pub const ALL: &str = "all";
@@ -93,12 +33,16 @@ struct Options {
     tabstops: Vec<usize>,
     aflag: bool,
     uflag: bool,
+
+    /// Strategy for generating tab stops for columns beyond those
+    /// specified in `tabstops`.
+    remaining_mode: RemainingMode,
 }
 
 impl Options {
-    fn new(matches: &clap::ArgMatches) -> Result<Self, ParseError> {
-        let tabstops = match matches.get_many::<String>(options::TABS) {
-            None => vec![DEFAULT_TABSTOP],
+    fn new(matches: &clap::ArgMatches) -> Result<Self, tabstops::TabStopsParseError> {
+        let (remaining_mode, tabstops) = match matches.get_many::<String>(options::TABS) {
+            None => (RemainingMode::None, vec![DEFAULT_TABSTOP]),
             Some(s) => tabstops_parse(&s.map(|s| s.as_str()).collect::<Vec<_>>().join(","))?,
         };
 
@@ -116,6 +60,7 @@ impl Options {
             tabstops,
             aflag,
             uflag,
+            remaining_mode,
         })
     }
 }
@@ -226,14 +171,8 @@ fn open(path: &str) -> UResult<BufReader<Box<dyn Read + 'static>>> {
     }
 }
 
-fn next_tabstop(tabstops: &[usize], col: usize) -> Option<usize> {
-    if tabstops.len() == 1 {
-        Some(tabstops[0] - col % tabstops[0])
-    } else {
-        // find next larger tab
-        // if there isn't one in the list, tab becomes a single space
-        tabstops.iter().find(|&&t| t > col).map(|t| t - col)
-    }
+fn next_tabstop(tabstops: &[usize], col: usize, remaining_mode: RemainingMode) -> Option<usize> {
+    tabstops::next_tabstop(tabstops, col, remaining_mode)
 }
 
 fn write_tabs(
@@ -244,13 +183,14 @@ fn write_tabs(
     prevtab: bool,
     init: bool,
     amode: bool,
+    remaining_mode: RemainingMode,
 ) {
     // This conditional establishes the following:
     // We never turn a single space before a non-blank into
     // a tab, unless it's at the start of the line.
     let ai = init || amode;
     if (ai && !prevtab && col > scol + 1) || (col > scol && (init || ai && prevtab)) {
-        while let Some(nts) = next_tabstop(tabstops, scol) {
+        while let Some(nts) = next_tabstop(tabstops, scol, remaining_mode) {
             if col < scol + nts {
                 break;
             }
@@ -335,7 +275,16 @@ fn unexpand_line(
     while byte < buf.len() {
         // when we have a finite number of columns, never convert past the last column
         if lastcol > 0 && col >= lastcol {
-            write_tabs(output, ts, scol, col, pctype == CharType::Tab, init, true);
+            write_tabs(
+                output,
+                ts,
+                scol,
+                col,
+                pctype == CharType::Tab,
+                init,
+                true,
+                options.remaining_mode,
+            );
             output.write_all(&buf[byte..])?;
             scol = col;
             break;
@@ -352,7 +301,7 @@ fn unexpand_line(
                 col += if ctype == CharType::Space {
                     1
                 } else {
-                    next_tabstop(ts, col).unwrap_or(1)
+                    next_tabstop(ts, col, options.remaining_mode).unwrap_or(1)
                 };
 
                 if !tabs_buffered {
@@ -370,6 +319,7 @@ fn unexpand_line(
                     pctype == CharType::Tab,
                     init,
                     options.aflag,
+                    options.remaining_mode,
                 );
                 init = false; // no longer at the start of a line
                 col = if ctype == CharType::Other {
@@ -391,7 +341,16 @@ fn unexpand_line(
     }
 
     // write out anything remaining
-    write_tabs(output, ts, scol, col, pctype == CharType::Tab, init, true);
+    write_tabs(
+        output,
+        ts,
+        scol,
+        col,
+        pctype == CharType::Tab,
+        init,
+        true,
+        options.remaining_mode,
+    );
     output.flush()?;
     buf.truncate(0); // clear out the buffer
 
@@ -402,7 +361,13 @@ fn unexpand(options: &Options) -> UResult<()> {
     let mut output = BufWriter::new(stdout());
     let ts = &options.tabstops[..];
     let mut buf = Vec::new();
-    let lastcol = if ts.len() > 1 { *ts.last().unwrap() } else { 0 };
+    // Once past the last explicit tab stop, keep converting if a remaining
+    // mode is in effect; otherwise the rest of the line is left untouched.
+    let lastcol = if ts.len() > 1 && options.remaining_mode == RemainingMode::None {
+        *ts.last().unwrap()
+    } else {
+        0
+    };
 
     for file in &options.files {
         let mut fh = match open(file) {
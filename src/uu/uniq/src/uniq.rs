@@ -3,6 +3,7 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 // spell-checker:ignore badoption
+use bstr::io::BufReadExt;
 use clap::{
     builder::ValueParser, crate_version, error::ContextKind, error::Error, error::ErrorKind, Arg,
     ArgAction, ArgMatches, Command,
@@ -66,42 +67,68 @@ macro_rules! write_line_terminator {
 }
 
 impl Uniq {
-    pub fn print_uniq(&self, reader: impl BufRead, mut writer: impl Write) -> UResult<()> {
+    pub fn print_uniq(&self, mut reader: impl BufRead, mut writer: impl Write) -> UResult<()> {
         let mut first_line_printed = false;
         let mut group_count = 1;
         let line_terminator = self.get_line_terminator();
-        let mut lines = reader.split(line_terminator);
-        let mut line = match lines.next() {
-            Some(l) => l?,
-            None => return Ok(()),
-        };
-
-        let writer = &mut writer;
-
-        // compare current `line` with consecutive lines (`next_line`) of the input
-        // and if needed, print `line` based on the command line options provided
-        for next_line in lines {
-            let next_line = next_line?;
-            if self.cmp_keys(&line, &next_line) {
-                if (group_count == 1 && !self.repeats_only)
-                    || (group_count > 1 && !self.uniques_only)
-                {
-                    self.print_line(writer, &line, group_count, first_line_printed)?;
-                    first_line_printed = true;
+        let mut line: Option<Vec<u8>> = None;
+        let mut error: Option<Box<dyn UError>> = None;
+
+        // Scan the input a record at a time, borrowing straight out of the
+        // reader's buffer instead of allocating a `Vec` per record like
+        // `BufRead::split` does. We still have to copy the record we're
+        // holding on to (`line`) whenever we start a new group, since we
+        // need to keep comparing it against the records that follow it.
+        reader
+            .for_byte_record(line_terminator, |next_line| {
+                let Some(current) = line.as_mut() else {
+                    line = Some(next_line.to_vec());
+                    return Ok(true);
+                };
+
+                if self.cmp_keys(current, next_line) {
+                    if (group_count == 1 && !self.repeats_only)
+                        || (group_count > 1 && !self.uniques_only)
+                    {
+                        if let Err(e) =
+                            self.print_line(&mut writer, current, group_count, first_line_printed)
+                        {
+                            error = Some(e);
+                            return Ok(false);
+                        }
+                        first_line_printed = true;
+                    }
+                    current.clear();
+                    current.extend_from_slice(next_line);
+                    group_count = 1;
+                } else {
+                    if self.all_repeated {
+                        if let Err(e) =
+                            self.print_line(&mut writer, current, group_count, first_line_printed)
+                        {
+                            error = Some(e);
+                            return Ok(false);
+                        }
+                        first_line_printed = true;
+                        current.clear();
+                        current.extend_from_slice(next_line);
+                    }
+                    group_count += 1;
                 }
-                line = next_line;
-                group_count = 1;
-            } else {
-                if self.all_repeated {
-                    self.print_line(writer, &line, group_count, first_line_printed)?;
-                    first_line_printed = true;
-                    line = next_line;
-                }
-                group_count += 1;
-            }
+                Ok(true)
+            })
+            .map_err_context(|| "Could not read line".to_string())?;
+
+        if let Some(e) = error {
+            return Err(e);
         }
+
+        let Some(line) = line else {
+            return Ok(());
+        };
+
         if (group_count == 1 && !self.repeats_only) || (group_count > 1 && !self.uniques_only) {
-            self.print_line(writer, &line, group_count, first_line_printed)?;
+            self.print_line(&mut writer, &line, group_count, first_line_printed)?;
             first_line_printed = true;
         }
         if (self.delimiters == Delimiters::Append || self.delimiters == Delimiters::Both)
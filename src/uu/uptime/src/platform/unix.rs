@@ -38,6 +38,11 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             return Ok(());
         }
 
+        if matches.get_flag(options::PRETTY) {
+            print_pretty_uptime(uptime);
+            return Ok(());
+        }
+
         print_time();
         let upsecs = uptime;
         print_uptime(upsecs);
@@ -147,6 +152,37 @@ fn get_uptime(_boot_time: Option<time_t>) -> i64 {
     unsafe { GetTickCount() as i64 }
 }
 
+/// Print the uptime in the human-readable `--pretty` format, e.g.
+/// `up 1 day, 2 hours, 3 minutes`, omitting any component that is zero.
+/// If the whole uptime is under a minute, prints `up 0 minutes`.
+fn print_pretty_uptime(upsecs: i64) {
+    let updays = upsecs / 86400;
+    let uphours = (upsecs % 86400) / 3600;
+    let upmins = (upsecs % 3600) / 60;
+
+    let mut parts = Vec::new();
+    if updays > 0 {
+        parts.push(format!(
+            "{updays} day{}",
+            if updays == 1 { "" } else { "s" }
+        ));
+    }
+    if uphours > 0 {
+        parts.push(format!(
+            "{uphours} hour{}",
+            if uphours == 1 { "" } else { "s" }
+        ));
+    }
+    if upmins > 0 || parts.is_empty() {
+        parts.push(format!(
+            "{upmins} minute{}",
+            if upmins == 1 { "" } else { "s" }
+        ));
+    }
+
+    println!("up {}", parts.join(", "));
+}
+
 fn print_uptime(upsecs: i64) {
     let updays = upsecs / 86400;
     let uphours = (upsecs - (updays * 86400)) / 3600;
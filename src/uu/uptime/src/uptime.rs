@@ -13,6 +13,7 @@ const ABOUT: &str = help_about!("uptime.md");
 const USAGE: &str = help_usage!("uptime.md");
 pub mod options {
     pub static SINCE: &str = "since";
+    pub static PRETTY: &str = "pretty";
 }
 
 #[uucore::main]
@@ -31,4 +32,11 @@ pub fn uu_app() -> Command {
                 .help("system up since")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(options::PRETTY)
+                .short('p')
+                .long(options::PRETTY)
+                .help("show uptime in pretty format")
+                .action(ArgAction::SetTrue),
+        )
 }
@@ -9,7 +9,7 @@ use clap::{builder::ValueParser, crate_version, Arg, ArgAction, Command};
 use std::error::Error;
 use std::ffi::OsString;
 use std::io::{self, Write};
-use uucore::error::{UResult, USimpleError};
+use uucore::error::{FromIo, UResult};
 #[cfg(unix)]
 use uucore::signals::enable_pipe_errors;
 use uucore::{format_usage, help_about, help_usage};
@@ -31,11 +31,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     args_into_buffer(&mut buffer, matches.get_many::<OsString>("STRING")).unwrap();
     prepare_buffer(&mut buffer);
 
-    match exec(&buffer) {
-        Ok(()) => Ok(()),
-        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
-        Err(err) => Err(USimpleError::new(1, format!("standard output: {err}"))),
-    }
+    exec(&buffer).map_err_context(|| "standard output".into())
 }
 
 pub fn uu_app() -> Command {
@@ -16,16 +16,25 @@ pub mod format;
 pub mod fs;
 #[cfg(feature = "fsext")]
 pub mod fsext;
+#[cfg(feature = "json")]
+pub mod json;
 #[cfg(feature = "lines")]
 pub mod lines;
+#[cfg(feature = "locale")]
+pub mod locale;
 #[cfg(feature = "quoting-style")]
 pub mod quoting_style;
+#[cfg(feature = "rand-read-adapter")]
+pub mod rand_read_adapter;
 #[cfg(feature = "ranges")]
 pub mod ranges;
 #[cfg(feature = "ringbuffer")]
 pub mod ringbuffer;
+pub mod selinux;
 #[cfg(feature = "sum")]
 pub mod sum;
+#[cfg(feature = "tabstops")]
+pub mod tabstops;
 #[cfg(feature = "update-control")]
 pub mod update_control;
 #[cfg(feature = "version-cmp")]
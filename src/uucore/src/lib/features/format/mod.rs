@@ -143,10 +143,9 @@ impl<C: FormatChar> FormatItem<C> {
         args: &mut impl Iterator<Item = &'a FormatArgument>,
     ) -> Result<ControlFlow<()>, FormatError> {
         match self {
-            Self::Spec(spec) => spec.write(writer, args)?,
-            Self::Char(c) => return c.write(writer).map_err(FormatError::IoError),
-        };
-        Ok(ControlFlow::Continue(()))
+            Self::Spec(spec) => spec.write(writer, args),
+            Self::Char(c) => c.write(writer).map_err(FormatError::IoError),
+        }
     }
 }
 
@@ -251,7 +250,9 @@ fn printf_writer<'a>(
 ) -> Result<(), FormatError> {
     let mut args = args.into_iter();
     for item in parse_spec_only(format_string.as_ref()) {
-        item?.write(&mut writer, &mut args)?;
+        if item?.write(&mut writer, &mut args)?.is_break() {
+            break;
+        }
     }
     Ok(())
 }
@@ -72,6 +72,7 @@ pub struct SignedInt {
     pub precision: usize,
     pub positive_sign: PositiveSign,
     pub alignment: NumberAlignment,
+    pub grouping: bool,
 }
 
 impl Formatter for SignedInt {
@@ -86,7 +87,10 @@ impl Formatter for SignedInt {
             }?;
         }
 
-        let s = format!("{:0width$}", x, width = self.precision);
+        let mut s = format!("{:0width$}", x, width = self.precision);
+        if self.grouping {
+            s = group_digits(&s);
+        }
 
         match self.alignment {
             NumberAlignment::Left => write!(writer, "{s:<width$}", width = self.width),
@@ -101,6 +105,7 @@ impl Formatter for SignedInt {
             precision,
             positive_sign,
             alignment,
+            grouping,
         } = s
         else {
             return Err(FormatError::WrongSpecType);
@@ -123,6 +128,7 @@ impl Formatter for SignedInt {
             precision,
             positive_sign,
             alignment,
+            grouping,
         })
     }
 }
@@ -132,6 +138,7 @@ pub struct UnsignedInt {
     pub width: usize,
     pub precision: usize,
     pub alignment: NumberAlignment,
+    pub grouping: bool,
 }
 
 impl Formatter for UnsignedInt {
@@ -159,6 +166,11 @@ impl Formatter for UnsignedInt {
         };
 
         s = format!("{prefix}{s:0>width$}", width = self.precision);
+        // Digit grouping is only meaningful for base 10; a bare `%'o` or
+        // `%'x` is silently ungrouped, the same as in glibc.
+        if self.grouping && matches!(self.variant, UnsignedIntVariant::Decimal) {
+            s = group_digits(&s);
+        }
 
         match self.alignment {
             NumberAlignment::Left => write!(writer, "{s:<width$}", width = self.width),
@@ -174,6 +186,7 @@ impl Formatter for UnsignedInt {
             precision,
             positive_sign: PositiveSign::None,
             alignment,
+            grouping,
         } = s
         {
             Spec::UnsignedInt {
@@ -181,6 +194,7 @@ impl Formatter for UnsignedInt {
                 width,
                 precision,
                 alignment,
+                grouping,
             }
         } else {
             s
@@ -191,6 +205,7 @@ impl Formatter for UnsignedInt {
             width,
             precision,
             alignment,
+            grouping,
         } = s
         else {
             return Err(FormatError::WrongSpecType);
@@ -213,10 +228,54 @@ impl Formatter for UnsignedInt {
             precision,
             variant,
             alignment,
+            grouping,
         })
     }
 }
 
+/// An exact decimal magnitude, expressed the same way
+/// `bigdecimal::BigDecimal::as_bigint_and_exponent` does: the value equals
+/// `digits * 10^-scale`, where `digits` holds only decimal digit
+/// characters (no sign).
+///
+/// This lets a caller backed by an arbitrary-precision decimal type (like
+/// `seq`'s `ExtendedBigDecimal`) hand [`Float`] the exact value to format,
+/// without uucore needing to depend on that caller's bignum crate.
+#[derive(Clone)]
+pub struct ExactDecimal {
+    pub negative: bool,
+    pub digits: String,
+    pub scale: i64,
+}
+
+impl ExactDecimal {
+    /// The nearest `f64` to this value, for the one variant (`%a`) that is
+    /// inherently binary and so has no meaningful arbitrary-precision
+    /// decimal rendering.
+    fn to_f64(&self) -> f64 {
+        let sign = if self.negative { "-" } else { "" };
+        format!("{sign}{}e{}", self.digits, -self.scale)
+            .parse()
+            .unwrap_or(0.0)
+    }
+}
+
+/// The value passed to [`Float::fmt`]. Most callers (like `printf`'s own
+/// argument parser) only ever have an `f64`; [`ExactDecimal`] lets a
+/// caller with an arbitrary-precision decimal value format it exactly,
+/// without first rounding it into an `f64`.
+#[derive(Clone)]
+pub enum FloatInput {
+    F64(f64),
+    Exact(ExactDecimal),
+}
+
+impl From<f64> for FloatInput {
+    fn from(x: f64) -> Self {
+        Self::F64(x)
+    }
+}
+
 pub struct Float {
     pub variant: FloatVariant,
     pub case: Case,
@@ -225,6 +284,7 @@ pub struct Float {
     pub positive_sign: PositiveSign,
     pub alignment: NumberAlignment,
     pub precision: usize,
+    pub grouping: bool,
 }
 
 impl Default for Float {
@@ -237,15 +297,37 @@ impl Default for Float {
             positive_sign: PositiveSign::None,
             alignment: NumberAlignment::Left,
             precision: 6,
+            grouping: false,
+        }
+    }
+}
+
+impl Float {
+    /// Digit grouping only has a defined meaning for decimal notation, so
+    /// `%'e`/`%'a` are silently left ungrouped, the same as glibc. `%g`/`%G`
+    /// (`Shortest`) can render in either notation depending on the value,
+    /// so only group when it actually came out in decimal form.
+    fn group_if_decimal(&self, s: &str) -> String {
+        if self.grouping
+            && matches!(self.variant, FloatVariant::Decimal | FloatVariant::Shortest)
+            && !s.contains(['e', 'E'])
+        {
+            group_float_digits(s)
+        } else {
+            s.to_string()
         }
     }
 }
 
 impl Formatter for Float {
-    type Input = f64;
+    type Input = FloatInput;
 
     fn fmt(&self, mut writer: impl Write, x: Self::Input) -> std::io::Result<()> {
-        if x.is_sign_positive() {
+        let positive = match &x {
+            FloatInput::F64(x) => x.is_sign_positive(),
+            FloatInput::Exact(d) => !d.negative,
+        };
+        if positive {
             match self.positive_sign {
                 PositiveSign::None => Ok(()),
                 PositiveSign::Plus => write!(writer, "+"),
@@ -253,23 +335,49 @@ impl Formatter for Float {
             }?;
         }
 
-        let s = if x.is_finite() {
-            match self.variant {
-                FloatVariant::Decimal => {
-                    format_float_decimal(x, self.precision, self.force_decimal)
-                }
-                FloatVariant::Scientific => {
-                    format_float_scientific(x, self.precision, self.case, self.force_decimal)
-                }
-                FloatVariant::Shortest => {
-                    format_float_shortest(x, self.precision, self.case, self.force_decimal)
-                }
-                FloatVariant::Hexadecimal => {
-                    format_float_hexadecimal(x, self.precision, self.case, self.force_decimal)
-                }
+        let s = match x {
+            FloatInput::F64(x) if !x.is_finite() => format_float_non_finite(x, self.case),
+            FloatInput::F64(x) => {
+                let s = match self.variant {
+                    FloatVariant::Decimal => {
+                        format_float_decimal(x, self.precision, self.force_decimal)
+                    }
+                    FloatVariant::Scientific => {
+                        format_float_scientific(x, self.precision, self.case, self.force_decimal)
+                    }
+                    FloatVariant::Shortest => {
+                        format_float_shortest(x, self.precision, self.case, self.force_decimal)
+                    }
+                    FloatVariant::Hexadecimal => {
+                        format_float_hexadecimal(x, self.precision, self.case, self.force_decimal)
+                    }
+                };
+                self.group_if_decimal(&s)
+            }
+            FloatInput::Exact(d) => {
+                let s = match self.variant {
+                    FloatVariant::Decimal => {
+                        format_exact_decimal(&d, self.precision, self.force_decimal)
+                    }
+                    FloatVariant::Scientific => {
+                        format_exact_scientific(&d, self.precision, self.case, self.force_decimal)
+                    }
+                    FloatVariant::Shortest => {
+                        format_exact_shortest(&d, self.precision, self.case, self.force_decimal)
+                    }
+                    // `%a` is inherently a binary-radix format; there is no
+                    // arbitrary-precision-decimal hex rendering to speak
+                    // of, so fall back to the nearest `f64`, the same as
+                    // every other `f64`-based formatter in this codebase.
+                    FloatVariant::Hexadecimal => format_float_hexadecimal(
+                        d.to_f64(),
+                        self.precision,
+                        self.case,
+                        self.force_decimal,
+                    ),
+                };
+                self.group_if_decimal(&s)
             }
-        } else {
-            format_float_non_finite(x, self.case)
         };
 
         match self.alignment {
@@ -291,6 +399,7 @@ impl Formatter for Float {
             positive_sign,
             alignment,
             precision,
+            grouping,
         } = s
         else {
             return Err(FormatError::WrongSpecType);
@@ -304,13 +413,15 @@ impl Formatter for Float {
 
         let precision = match precision {
             Some(CanAsterisk::Fixed(x)) => x,
-            None => {
-                if matches!(variant, FloatVariant::Shortest) {
-                    6
-                } else {
-                    0
-                }
-            }
+            None => match variant {
+                FloatVariant::Shortest => 6,
+                // No explicit precision means "use exactly as many hex
+                // digits as needed to represent the value exactly",
+                // which `format_float_hexadecimal` recognizes via this
+                // sentinel rather than a fixed digit count.
+                FloatVariant::Hexadecimal => HEX_PRECISION_UNSPECIFIED,
+                _ => 0,
+            },
             Some(CanAsterisk::Asterisk) => return Err(FormatError::WrongSpecType),
         };
 
@@ -322,10 +433,46 @@ impl Formatter for Float {
             positive_sign,
             alignment,
             precision,
+            grouping,
         })
     }
 }
 
+/// Insert thousands separators into the digits of `s`, which is expected to
+/// be a plain (undecorated) integer, optionally with a leading `-`.
+///
+/// The `'` flag groups digits the way the current `LC_NUMERIC` locale does:
+/// with the locale's own separator and group size, or a comma every three
+/// digits in the "C" locale (and on platforms without locale support).
+fn group_digits(s: &str) -> String {
+    let (sign, digits) = match s.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", s),
+    };
+
+    let separator = crate::locale::thousands_sep().unwrap_or(',');
+    let size = crate::locale::group_size().unwrap_or(3);
+
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / size);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % size == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+
+    format!("{sign}{grouped}")
+}
+
+/// Like [`group_digits`], but for a formatted float: only the digits before
+/// the decimal point are grouped, the fractional part is left untouched.
+fn group_float_digits(s: &str) -> String {
+    match s.split_once('.') {
+        Some((integral, fractional)) => format!("{}.{fractional}", group_digits(integral)),
+        None => group_digits(s),
+    }
+}
+
 fn format_float_non_finite(f: f64, case: Case) -> String {
     debug_assert!(!f.is_finite());
     let mut s = format!("{f}");
@@ -335,6 +482,13 @@ fn format_float_non_finite(f: f64, case: Case) -> String {
     s
 }
 
+/// Round `f` to `precision` decimal digits and render it.
+///
+/// Rounding is delegated to Rust's own float formatting (`{:.*}`), which
+/// is correctly rounded and resolves exact ties to the nearest even
+/// digit, the same rounding mode glibc's `printf` uses. This matters at
+/// low precisions (e.g. `%.0f` on `0.5`), where truncation or
+/// round-half-away-from-zero would disagree with GNU's output.
 fn format_float_decimal(f: f64, precision: usize, force_decimal: ForceDecimal) -> String {
     if precision == 0 && force_decimal == ForceDecimal::Yes {
         format!("{f:.0}.")
@@ -350,10 +504,13 @@ fn format_float_scientific(
     force_decimal: ForceDecimal,
 ) -> String {
     if f == 0.0 {
+        // As in `format_float_shortest`, format from `f` itself so a
+        // negative zero keeps its sign.
+        let sign = if f.is_sign_negative() { "-" } else { "" };
         return if force_decimal == ForceDecimal::Yes && precision == 0 {
-            "0.e+00".into()
+            format!("{sign}0.e+00")
         } else {
-            format!("{:.*}e+00", precision, 0.0)
+            format!("{sign}{:.*}e+00", precision, 0.0)
         };
     }
 
@@ -397,12 +554,14 @@ fn format_float_shortest(
     let precision = precision.saturating_sub(1);
 
     if f == 0.0 {
+        // `f == 0.0` is also true for `-0.0`; format from `f` itself
+        // (rather than a literal `0.0`) so the sign of a negative zero
+        // is preserved, matching glibc's `printf("%g", -0.0)`.
+        let sign = if f.is_sign_negative() { "-" } else { "" };
         return match (force_decimal, precision) {
-            (ForceDecimal::Yes, 0) => "0.".into(),
-            (ForceDecimal::Yes, _) => {
-                format!("{:.*}", precision, 0.0)
-            }
-            (ForceDecimal::No, _) => "0".into(),
+            (ForceDecimal::Yes, 0) => format!("{sign}0."),
+            (ForceDecimal::Yes, _) => format!("{:.*}", precision, f),
+            (ForceDecimal::No, _) => format!("{sign}0"),
         };
     }
 
@@ -459,26 +618,91 @@ fn format_float_shortest(
     }
 }
 
+/// Sentinel `precision` meaning "no explicit precision was given": print
+/// exactly as many fraction digits as are needed to represent the value
+/// exactly, trimming the rest, rather than rounding to a fixed count.
+pub(crate) const HEX_PRECISION_UNSPECIFIED: usize = usize::MAX;
+
+/// Format `f` as a C99/printf-style hexadecimal float (`%a`).
+///
+/// glibc does not normalize the leading digit down to `1`: it keeps a
+/// full hex nibble for the leading digit (so it ranges `8`-`f`, using
+/// the implicit leading mantissa bit as that nibble's high bit), which
+/// shifts the exponent down by 3 relative to the naive "one leading
+/// bit" normalization. Matching that exactly is what makes `1.0` print
+/// as `0x8p-3` (not `0x1p+0`), the same as GNU's `seq`/`printf`.
 fn format_float_hexadecimal(
     f: f64,
     precision: usize,
     case: Case,
     force_decimal: ForceDecimal,
 ) -> String {
-    let (first_digit, mantissa, exponent) = if f == 0.0 {
-        (0, 0, 0)
+    let sign = if f.is_sign_negative() { "-" } else { "" };
+    let f = f.abs();
+
+    if f == 0.0 {
+        let frac_digits = if precision == HEX_PRECISION_UNSPECIFIED {
+            0
+        } else {
+            precision
+        };
+        let mut s = match (frac_digits, force_decimal) {
+            (0, ForceDecimal::No) => format!("{sign}0x0p+0"),
+            (0, ForceDecimal::Yes) => format!("{sign}0x0.p+0"),
+            (_, _) => format!("{sign}0x0.{:0<frac_digits$}p+0", ""),
+        };
+        if case == Case::Uppercase {
+            s.make_ascii_uppercase();
+        }
+        return s;
+    }
+
+    let bits = f.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7ff) as i64 - 1023 - 3;
+    let mantissa = bits & 0xf_ffff_ffff_ffff;
+    // The implicit leading mantissa bit, followed by the 52 explicit
+    // mantissa bits, followed by 3 zero padding bits: 56 bits, or 14
+    // hex digits, giving the leading digit a full nibble (its top bit
+    // is always the implicit 1).
+    let value = (1u64 << 55) | (mantissa << 3);
+
+    // Total number of hex digits to print, leading digit included.
+    let total_digits = if precision == HEX_PRECISION_UNSPECIFIED {
+        14
     } else {
-        let bits = f.to_bits();
-        let exponent_bits = ((bits >> 52) & 0x7fff) as i64;
-        let exponent = exponent_bits - 1023;
-        let mantissa = bits & 0xf_ffff_ffff_ffff;
-        (1, mantissa, exponent)
+        precision + 1
     };
 
-    let mut s = match (precision, force_decimal) {
-        (0, ForceDecimal::No) => format!("0x{first_digit}p{exponent:+x}"),
-        (0, ForceDecimal::Yes) => format!("0x{first_digit}.p{exponent:+x}"),
-        _ => format!("0x{first_digit}.{mantissa:0>13x}p{exponent:+x}"),
+    let digit_str = if total_digits >= 14 {
+        format!("{value:014x}{:0<pad$}", "", pad = total_digits - 14)
+    } else {
+        // Round the dropped low-order nibbles to nearest, ties to even.
+        let drop_bits = (14 - total_digits) as u32 * 4;
+        let half = 1u64 << (drop_bits - 1);
+        let remainder = value & ((1u64 << drop_bits) - 1);
+        let mut truncated = value >> drop_bits;
+        if remainder > half || (remainder == half && truncated & 1 == 1) {
+            truncated += 1;
+        }
+        // A carry out of the kept digits bumps the exponent by one nibble.
+        if truncated >> (total_digits as u32 * 4) != 0 {
+            truncated >>= 4;
+            exponent += 4;
+        }
+        format!("{truncated:0width$x}", width = total_digits)
+    };
+
+    let (first_digit, fraction) = digit_str.split_at(1);
+    let fraction = if precision == HEX_PRECISION_UNSPECIFIED {
+        fraction.trim_end_matches('0')
+    } else {
+        fraction
+    };
+
+    let mut s = match (fraction.is_empty(), force_decimal) {
+        (true, ForceDecimal::No) => format!("{sign}0x{first_digit}p{exponent:+}"),
+        (true, ForceDecimal::Yes) => format!("{sign}0x{first_digit}.p{exponent:+}"),
+        (false, _) => format!("{sign}0x{first_digit}.{fraction}p{exponent:+}"),
     };
 
     if case == Case::Uppercase {
@@ -501,10 +725,210 @@ fn strip_fractional_zeroes_and_dot(s: &mut String) {
     }
 }
 
+/// Split `digits * 10^-scale` (`digits` holding no sign, and assumed
+/// nonempty) into its integral and fractional decimal digit strings,
+/// exactly, with no rounding: the same before/after split
+/// `bigdecimal::BigDecimal`'s own `Display` impl computes.
+fn decimal_parts(digits: &str, scale: i64) -> (String, String) {
+    if scale <= 0 {
+        (format!("{digits}{}", "0".repeat((-scale) as usize)), String::new())
+    } else {
+        let scale = scale as usize;
+        if scale >= digits.len() {
+            (
+                "0".to_string(),
+                format!("{}{digits}", "0".repeat(scale - digits.len())),
+            )
+        } else {
+            let split = digits.len() - scale;
+            (digits[..split].to_string(), digits[split..].to_string())
+        }
+    }
+}
+
+/// Round the decimal digit string `before` + `.` + `after` to `precision`
+/// fractional digits, rounding half to even on the first dropped digit
+/// (the same rounding mode `format_float_decimal` documents for the `f64`
+/// case). Returns the resulting integral and fractional digit strings;
+/// the integral string may be one digit longer than `before` if rounding
+/// carried all the way out (e.g. `"99" + ".95"` at 0 digits becomes
+/// `"100"` + `""`).
+fn round_decimal(before: &str, after: &str, precision: usize) -> (String, String) {
+    if after.len() <= precision {
+        return (before.to_string(), format!("{after:0<precision$}"));
+    }
+
+    let (keep, drop) = after.split_at(precision);
+    let mut digits: Vec<u8> = before.bytes().chain(keep.bytes()).collect();
+
+    let first_dropped = drop.as_bytes()[0] - b'0';
+    let round_up = first_dropped > 5
+        || (first_dropped == 5
+            && (drop[1..].bytes().any(|b| b != b'0')
+                || digits.last().is_some_and(|&d| (d - b'0') % 2 == 1)));
+
+    if round_up {
+        let mut i = digits.len();
+        loop {
+            if i == 0 {
+                digits.insert(0, b'1');
+                break;
+            }
+            i -= 1;
+            if digits[i] == b'9' {
+                digits[i] = b'0';
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+    }
+
+    let integral_len = digits.len() - precision;
+    let after = digits.split_off(integral_len);
+    (
+        String::from_utf8(digits).unwrap(),
+        String::from_utf8(after).unwrap(),
+    )
+}
+
+/// Like [`round_decimal`], but starting from the exact `digits * 10^-scale`
+/// value rather than an already-split integral/fractional pair.
+fn round_fixed(digits: &str, scale: i64, precision: usize) -> (String, String) {
+    let (before, after) = decimal_parts(digits, scale);
+    round_decimal(&before, &after, precision)
+}
+
+/// Round `digits * 10^-scale` to a single leading digit plus `precision`
+/// fractional digits (i.e. normalized scientific form), returning
+/// `(leading_digit, fractional_digits, exponent)`. `exponent` already
+/// accounts for a carry that bumped the value up an order of magnitude
+/// (e.g. rounding `9.99` to one digit becomes `1` * 10^(exponent + 1)).
+fn round_scientific(digits: &str, scale: i64, precision: usize) -> (String, String, i64) {
+    let mut exponent = digits.len() as i64 - 1 - scale;
+    let (before, after) = round_decimal(&digits[..1], &digits[1..], precision);
+    if before.len() > 1 {
+        exponent += (before.len() - 1) as i64;
+    }
+    (before[..1].to_string(), after, exponent)
+}
+
+fn format_exact_decimal(d: &ExactDecimal, precision: usize, force_decimal: ForceDecimal) -> String {
+    let sign = if d.negative { "-" } else { "" };
+    let (before, after) = round_fixed(&d.digits, d.scale, precision);
+    match (after.is_empty(), force_decimal) {
+        (true, ForceDecimal::No) => format!("{sign}{before}"),
+        (true, ForceDecimal::Yes) => format!("{sign}{before}."),
+        (false, _) => format!("{sign}{before}.{after}"),
+    }
+}
+
+fn format_exact_scientific(
+    d: &ExactDecimal,
+    precision: usize,
+    case: Case,
+    force_decimal: ForceDecimal,
+) -> String {
+    let sign = if d.negative { "-" } else { "" };
+    let exp_char = match case {
+        Case::Lowercase => 'e',
+        Case::Uppercase => 'E',
+    };
+
+    if d.digits.bytes().all(|b| b == b'0') {
+        return if force_decimal == ForceDecimal::Yes && precision == 0 {
+            format!("{sign}0.e+00")
+        } else {
+            format!("{sign}{:.*}e+00", precision, 0.0)
+        };
+    }
+
+    let (lead, frac, exponent) = round_scientific(&d.digits, d.scale, precision);
+    let additional_dot = if precision == 0 && force_decimal == ForceDecimal::Yes {
+        "."
+    } else {
+        ""
+    };
+    let frac = if frac.is_empty() {
+        String::new()
+    } else {
+        format!(".{frac}")
+    };
+
+    format!("{sign}{lead}{frac}{additional_dot}{exp_char}{exponent:+03}")
+}
+
+fn format_exact_shortest(
+    d: &ExactDecimal,
+    precision: usize,
+    case: Case,
+    force_decimal: ForceDecimal,
+) -> String {
+    let precision = precision.saturating_sub(1);
+    let sign = if d.negative { "-" } else { "" };
+
+    if d.digits.bytes().all(|b| b == b'0') {
+        return match (force_decimal, precision) {
+            (ForceDecimal::Yes, 0) => format!("{sign}0."),
+            (ForceDecimal::Yes, _) => format!("{sign}{:.*}", precision, 0.0),
+            (ForceDecimal::No, _) => format!("{sign}0"),
+        };
+    }
+
+    let exponent = d.digits.len() as i64 - 1 - d.scale;
+    if exponent <= -4 || exponent > precision as i64 {
+        let (lead, frac, exponent) = round_scientific(&d.digits, d.scale, precision);
+        let additional_dot = if precision == 0 && force_decimal == ForceDecimal::Yes {
+            "."
+        } else {
+            ""
+        };
+        let mut normalized = if frac.is_empty() {
+            lead
+        } else {
+            format!("{lead}.{frac}")
+        };
+        if force_decimal == ForceDecimal::No {
+            strip_fractional_zeroes_and_dot(&mut normalized);
+        }
+
+        let exp_char = match case {
+            Case::Lowercase => 'e',
+            Case::Uppercase => 'E',
+        };
+
+        format!("{sign}{normalized}{additional_dot}{exp_char}{exponent:+03}")
+    } else {
+        let decimal_places = (precision as i64 - exponent) as usize;
+        let (before, after) = round_fixed(&d.digits, d.scale, decimal_places);
+        let mut formatted = if after.is_empty() && force_decimal == ForceDecimal::Yes {
+            format!("{before}.")
+        } else if after.is_empty() {
+            before
+        } else {
+            format!("{before}.{after}")
+        };
+
+        if force_decimal == ForceDecimal::No {
+            strip_fractional_zeroes_and_dot(&mut formatted);
+        }
+
+        format!("{sign}{formatted}")
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::format::num_format::{Case, ForceDecimal};
 
+    fn exact(negative: bool, digits: &str, scale: i64) -> super::ExactDecimal {
+        super::ExactDecimal {
+            negative,
+            digits: digits.to_string(),
+            scale,
+        }
+    }
+
     #[test]
     fn unsigned_octal() {
         use super::{Formatter, NumberAlignment, Prefix, UnsignedInt, UnsignedIntVariant};
@@ -515,6 +939,7 @@ mod test {
                 width: 0,
                 precision: 0,
                 alignment: NumberAlignment::Left,
+                grouping: false,
             }
             .fmt(&mut s, x)
             .unwrap();
@@ -526,6 +951,27 @@ mod test {
         assert_eq!(f(8), "010");
     }
 
+    #[test]
+    fn digit_grouping() {
+        use super::group_digits;
+        assert_eq!(group_digits("0"), "0");
+        assert_eq!(group_digits("12"), "12");
+        assert_eq!(group_digits("123"), "123");
+        assert_eq!(group_digits("1234"), "1,234");
+        assert_eq!(group_digits("1234567"), "1,234,567");
+        assert_eq!(group_digits("-1234567"), "-1,234,567");
+        assert_eq!(group_digits("-123"), "-123");
+    }
+
+    #[test]
+    fn float_digit_grouping() {
+        use super::group_float_digits;
+        assert_eq!(group_float_digits("1234567.891000"), "1,234,567.891000");
+        assert_eq!(group_float_digits("-1234567.891000"), "-1,234,567.891000");
+        assert_eq!(group_float_digits("123.456"), "123.456");
+        assert_eq!(group_float_digits("1234567"), "1,234,567");
+    }
+
     #[test]
     fn decimal_float() {
         use super::format_float_decimal;
@@ -625,6 +1071,122 @@ mod test {
         assert_eq!(f(99999999.0), "1.e+08");
     }
 
+    /// `format_exact_decimal` must round the exact digit string itself
+    /// rather than routing through `f64`, so it stays correct past `f64`'s
+    /// 53-bit integer precision, where `format_float_decimal` would not.
+    #[test]
+    fn exact_decimal() {
+        use super::format_exact_decimal;
+        let f = |d| format_exact_decimal(&d, 0, ForceDecimal::No);
+        assert_eq!(f(exact(false, "100000000000000000001", 0)), "100000000000000000001");
+        assert_eq!(f(exact(true, "100000000000000000001", 0)), "-100000000000000000001");
+        assert_eq!(f(exact(false, "0", 0)), "0");
+
+        let f = |d| format_exact_decimal(&d, 3, ForceDecimal::No);
+        assert_eq!(f(exact(false, "15", 1)), "1.500");
+        assert_eq!(f(exact(false, "15", 3)), "0.015");
+
+        // Rounding half to even on the first dropped digit, same as the
+        // `f64`-based `format_float_decimal`.
+        let f = |d| format_exact_decimal(&d, 0, ForceDecimal::No);
+        assert_eq!(f(exact(false, "995", 2)), "10");
+        assert_eq!(f(exact(false, "985", 2)), "10");
+        assert_eq!(f(exact(false, "975", 2)), "10");
+    }
+
+    #[test]
+    fn exact_scientific() {
+        use super::format_exact_scientific;
+        let f = |d| format_exact_scientific(&d, 6, Case::Lowercase, ForceDecimal::No);
+        assert_eq!(f(exact(false, "100000000000000000001", 0)), "1.000000e+20");
+        assert_eq!(f(exact(false, "0", 0)), "0.000000e+00");
+
+        let f = |d| format_exact_scientific(&d, 0, Case::Lowercase, ForceDecimal::No);
+        assert_eq!(f(exact(false, "995", 2)), "1e+01");
+    }
+
+    #[test]
+    fn exact_shortest() {
+        use super::format_exact_shortest;
+        let f = |d| format_exact_shortest(&d, 6, Case::Lowercase, ForceDecimal::No);
+        assert_eq!(f(exact(false, "100000000000000000001", 0)), "1e+20");
+        assert_eq!(f(exact(false, "15", 1)), "1.5");
+        assert_eq!(f(exact(false, "0", 0)), "0");
+    }
+
+    #[test]
+    fn negative_zero_keeps_its_sign() {
+        use super::{format_float_decimal, format_float_scientific, format_float_shortest};
+        assert_eq!(format_float_decimal(-0.0, 2, ForceDecimal::No), "-0.00");
+        assert_eq!(
+            format_float_scientific(-0.0, 2, Case::Lowercase, ForceDecimal::No),
+            "-0.00e+00"
+        );
+        assert_eq!(
+            format_float_shortest(-0.0, 6, Case::Lowercase, ForceDecimal::No),
+            "-0"
+        );
+        assert_eq!(
+            format_float_shortest(-0.0, 6, Case::Lowercase, ForceDecimal::Yes),
+            "-0.00000"
+        );
+    }
+
+    #[test]
+    fn hexadecimal_float_default_precision() {
+        use super::format_float_hexadecimal;
+        let f = |x| format_float_hexadecimal(x, usize::MAX, Case::Lowercase, ForceDecimal::No);
+        // glibc's `%a` keeps a full nibble for the leading digit (using
+        // the implicit mantissa bit as its high bit) instead of
+        // normalizing it down to `1`, so `1.0` is `0x8p-3`, not `0x1p+0`.
+        assert_eq!(f(1.0), "0x8p-3");
+        assert_eq!(f(2.0), "0x8p-2");
+        assert_eq!(f(0.875), "0xep-4");
+        assert_eq!(f(1.5), "0xcp-3");
+        assert_eq!(f(255.0), "0xf.fp+4");
+        assert_eq!(f(256.0), "0x8p+5");
+        assert_eq!(f(0.0), "0x0p+0");
+        assert_eq!(f(-2.0), "-0x8p-2");
+    }
+
+    #[test]
+    fn hexadecimal_float_explicit_precision() {
+        use super::format_float_hexadecimal;
+        assert_eq!(
+            format_float_hexadecimal(1.0, 3, Case::Lowercase, ForceDecimal::No),
+            "0x8.000p-3"
+        );
+        assert_eq!(
+            format_float_hexadecimal(1.0, 0, Case::Lowercase, ForceDecimal::No),
+            "0x8p-3"
+        );
+        assert_eq!(
+            format_float_hexadecimal(1.0, 0, Case::Lowercase, ForceDecimal::Yes),
+            "0x8.p-3"
+        );
+        assert_eq!(
+            format_float_hexadecimal(0.875, 1, Case::Uppercase, ForceDecimal::No),
+            "0XE.0P-4"
+        );
+    }
+
+    /// Rounding the dropped low-order nibbles up can itself overflow the
+    /// digits that were kept (all `f`s rounding to `10`), which must bump
+    /// the exponent by one nibble rather than just carrying into the
+    /// leading digit's high bit.
+    #[test]
+    fn hexadecimal_float_rounding_carries_into_exponent() {
+        use super::format_float_hexadecimal;
+        // The largest `f64` less than `2.0`: `0xf.ffffffffffff8p-3`.
+        // Rounding that to zero fraction digits overflows to `0x10p-3`,
+        // which renormalizes to `0x1p+1`.
+        let x = f64::from_bits(2.0f64.to_bits() - 1);
+        assert_eq!(
+            format_float_hexadecimal(x, 0, Case::Lowercase, ForceDecimal::No),
+            "0x1p+1"
+        );
+    }
+
     #[test]
     fn strip_insignificant_end() {
         use super::strip_fractional_zeroes_and_dot;
@@ -38,12 +38,14 @@ pub enum Spec {
         precision: Option<CanAsterisk<usize>>,
         positive_sign: PositiveSign,
         alignment: NumberAlignment,
+        grouping: bool,
     },
     UnsignedInt {
         variant: UnsignedIntVariant,
         width: Option<CanAsterisk<usize>>,
         precision: Option<CanAsterisk<usize>>,
         alignment: NumberAlignment,
+        grouping: bool,
     },
     Float {
         variant: FloatVariant,
@@ -53,6 +55,7 @@ pub enum Spec {
         positive_sign: PositiveSign,
         alignment: NumberAlignment,
         precision: Option<CanAsterisk<usize>>,
+        grouping: bool,
     },
 }
 
@@ -94,6 +97,7 @@ struct Flags {
     space: bool,
     hash: bool,
     zero: bool,
+    apostrophe: bool,
 }
 
 impl Flags {
@@ -107,6 +111,7 @@ impl Flags {
                 b' ' => flags.space = true,
                 b'#' => flags.hash = true,
                 b'0' => flags.zero = true,
+                b'\'' => flags.apostrophe = true,
                 _ => break,
             }
             *index += 1;
@@ -210,6 +215,7 @@ impl Spec {
                     precision,
                     alignment,
                     positive_sign,
+                    grouping: flags.apostrophe,
                 }
             }
             c @ (b'u' | b'o' | b'x' | b'X') => {
@@ -230,6 +236,10 @@ impl Spec {
                     precision,
                     width,
                     alignment,
+                    // Digit grouping only has a defined meaning for base
+                    // 10, so it is silently ignored for %o/%x/%X, the
+                    // same way glibc ignores it there.
+                    grouping: flags.apostrophe,
                 }
             }
             c @ (b'f' | b'F' | b'e' | b'E' | b'g' | b'G' | b'a' | b'A') => Self::Float {
@@ -254,6 +264,9 @@ impl Spec {
                 },
                 alignment,
                 positive_sign,
+                // As with %o/%x/%X above, grouping is only meaningful in
+                // decimal notation, so %e/%a variants ignore it.
+                grouping: flags.apostrophe,
             },
             _ => return Err(&start[..index]),
         })
@@ -307,11 +320,12 @@ impl Spec {
         &self,
         mut writer: impl Write,
         mut args: impl ArgumentIter<'a>,
-    ) -> Result<(), FormatError> {
+    ) -> Result<ControlFlow<()>, FormatError> {
         match self {
             Self::Char { width, align_left } => {
                 let width = resolve_asterisk(*width, &mut args)?.unwrap_or(0);
-                write_padded(writer, &[args.get_char()], width, *align_left)
+                write_padded(writer, &[args.get_char()], width, *align_left)?;
+                Ok(ControlFlow::Continue(()))
             }
             Self::String {
                 width,
@@ -332,21 +346,26 @@ impl Spec {
                     Some(p) if p < s.len() => &s[..p],
                     _ => s,
                 };
-                write_padded(writer, truncated.as_bytes(), width, *align_left)
+                write_padded(writer, truncated.as_bytes(), width, *align_left)?;
+                Ok(ControlFlow::Continue(()))
             }
             Self::EscapedString => {
                 let s = args.get_str();
                 let mut parsed = Vec::new();
+                let mut stop = ControlFlow::Continue(());
                 for c in parse_escape_only(s.as_bytes()) {
                     match c.write(&mut parsed)? {
                         ControlFlow::Continue(()) => {}
                         ControlFlow::Break(()) => {
-                            // TODO: This should break the _entire execution_ of printf
+                            // `\c` in a %b argument stops all further output,
+                            // not just the rest of this argument.
+                            stop = ControlFlow::Break(());
                             break;
                         }
                     };
                 }
-                writer.write_all(&parsed).map_err(FormatError::IoError)
+                writer.write_all(&parsed).map_err(FormatError::IoError)?;
+                Ok(stop)
             }
             Self::QuotedString => {
                 let s = args.get_str();
@@ -362,13 +381,15 @@ impl Spec {
                         )
                         .as_bytes(),
                     )
-                    .map_err(FormatError::IoError)
+                    .map_err(FormatError::IoError)?;
+                Ok(ControlFlow::Continue(()))
             }
             Self::SignedInt {
                 width,
                 precision,
                 positive_sign,
                 alignment,
+                grouping,
             } => {
                 let width = resolve_asterisk(*width, &mut args)?.unwrap_or(0);
                 let precision = resolve_asterisk(*precision, &mut args)?.unwrap_or(0);
@@ -379,15 +400,18 @@ impl Spec {
                     precision,
                     positive_sign: *positive_sign,
                     alignment: *alignment,
+                    grouping: *grouping,
                 }
                 .fmt(writer, i)
-                .map_err(FormatError::IoError)
+                .map_err(FormatError::IoError)?;
+                Ok(ControlFlow::Continue(()))
             }
             Self::UnsignedInt {
                 variant,
                 width,
                 precision,
                 alignment,
+                grouping,
             } => {
                 let width = resolve_asterisk(*width, &mut args)?.unwrap_or(0);
                 let precision = resolve_asterisk(*precision, &mut args)?.unwrap_or(0);
@@ -398,9 +422,11 @@ impl Spec {
                     precision,
                     width,
                     alignment: *alignment,
+                    grouping: *grouping,
                 }
                 .fmt(writer, i)
-                .map_err(FormatError::IoError)
+                .map_err(FormatError::IoError)?;
+                Ok(ControlFlow::Continue(()))
             }
             Self::Float {
                 variant,
@@ -410,9 +436,18 @@ impl Spec {
                 positive_sign,
                 alignment,
                 precision,
+                grouping,
             } => {
                 let width = resolve_asterisk(*width, &mut args)?.unwrap_or(0);
-                let precision = resolve_asterisk(*precision, &mut args)?.unwrap_or(6);
+                let default_precision = match variant {
+                    // No explicit precision means "use exactly as many
+                    // hex digits as needed for an exact representation",
+                    // not the usual default of 6 fractional digits.
+                    num_format::FloatVariant::Hexadecimal => num_format::HEX_PRECISION_UNSPECIFIED,
+                    _ => 6,
+                };
+                let precision =
+                    resolve_asterisk(*precision, &mut args)?.unwrap_or(default_precision);
                 let f = args.get_f64();
 
                 num_format::Float {
@@ -423,9 +458,11 @@ impl Spec {
                     force_decimal: *force_decimal,
                     positive_sign: *positive_sign,
                     alignment: *alignment,
+                    grouping: *grouping,
                 }
-                .fmt(writer, f)
-                .map_err(FormatError::IoError)
+                .fmt(writer, f.into())
+                .map_err(FormatError::IoError)?;
+                Ok(ControlFlow::Continue(()))
             }
         }
     }
@@ -0,0 +1,165 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! A small JSON writer for utilities with a `--json` output mode.
+//!
+//! This only supports enough of JSON to describe the flat, per-record
+//! output that utilities like `du` or `stat` produce: objects, arrays,
+//! strings, numbers, booleans and null. It exists so that every utility's
+//! `--json` mode agrees on quoting and escaping instead of each one
+//! hand-rolling its own `format!("{{\"a\":...}}")`.
+
+use std::fmt::{self, Write as _};
+
+/// A JSON value.
+///
+/// [`JsonValue::Object`] keeps fields in insertion order rather than
+/// sorting them, so a utility's `--json` output lists fields in the same
+/// order as its normal, column-based output.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Build an object from a list of `(key, value)` pairs, in order.
+    pub fn object(fields: impl IntoIterator<Item = (&'static str, JsonValue)>) -> Self {
+        Self::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for JsonValue {
+                fn from(n: $ty) -> Self {
+                    Self::Number(n.to_string())
+                }
+            }
+        )*
+    };
+}
+impl_from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<bool> for JsonValue {
+    fn from(b: bool) -> Self {
+        Self::Bool(b)
+    }
+}
+
+impl From<&str> for JsonValue {
+    fn from(s: &str) -> Self {
+        Self::String(s.to_string())
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
+impl<T: Into<JsonValue>> From<Option<T>> for JsonValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+fn write_escaped_str(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Number(n) => f.write_str(n),
+            Self::String(s) => write_escaped_str(f, s),
+            Self::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_char(']')
+            }
+            Self::Object(fields) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_escaped_str(f, key)?;
+                    f.write_char(':')?;
+                    write!(f, "{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonValue;
+
+    #[test]
+    fn scalars() {
+        assert_eq!(JsonValue::Null.to_string(), "null");
+        assert_eq!(JsonValue::from(true).to_string(), "true");
+        assert_eq!(JsonValue::from(42u64).to_string(), "42");
+        assert_eq!(JsonValue::from("hi").to_string(), "\"hi\"");
+        assert_eq!(JsonValue::from(None::<u64>).to_string(), "null");
+        assert_eq!(JsonValue::from(Some(1u64)).to_string(), "1");
+    }
+
+    #[test]
+    fn escapes_special_characters() {
+        assert_eq!(
+            JsonValue::from("a\"b\\c\nd").to_string(),
+            "\"a\\\"b\\\\c\\nd\""
+        );
+    }
+
+    #[test]
+    fn object_preserves_field_order() {
+        let value = JsonValue::object([("b", JsonValue::from(1u64)), ("a", JsonValue::from(2u64))]);
+        assert_eq!(value.to_string(), r#"{"b":1,"a":2}"#);
+    }
+
+    #[test]
+    fn array_of_objects() {
+        let value = JsonValue::Array(vec![
+            JsonValue::object([("name", JsonValue::from("a"))]),
+            JsonValue::object([("name", JsonValue::from("b"))]),
+        ]);
+        assert_eq!(value.to_string(), r#"[{"name":"a"},{"name":"b"}]"#);
+    }
+}
@@ -0,0 +1,140 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Read the handful of `LC_NUMERIC`/`LC_COLLATE` settings that utilities
+//! actually need: the decimal-point character, the thousands-grouping
+//! separator and size, and locale-aware string collation.
+//!
+//! This is intentionally narrow. It does not attempt full POSIX locale
+//! support (multi-size groupings, `LC_MESSAGES`, encoding conversion,
+//! ...); it covers the common case of a single repeated grouping size,
+//! which is what every locale glibc ships actually uses. On non-Unix
+//! platforms, or if the requested locale can't be set, every function
+//! here falls back to the same "C" locale behavior uutils has always had.
+//!
+//! [`init`] calls `setlocale(LC_ALL, "")`, which reads `LC_ALL`/`LC_*`/
+//! `LANG` from the environment; it is called once, lazily, the first time
+//! any function in this module is used.
+
+use std::cmp::Ordering;
+#[cfg(unix)]
+use std::ffi::{CStr, CString};
+
+#[cfg(unix)]
+fn init() {
+    use std::sync::Once;
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| unsafe {
+        libc::setlocale(libc::LC_ALL, b"\0".as_ptr().cast());
+    });
+}
+
+#[cfg(unix)]
+unsafe fn lconv_char(ptr: *mut libc::c_char) -> Option<char> {
+    if ptr.is_null() {
+        return None;
+    }
+    CStr::from_ptr(ptr).to_str().ok()?.chars().next()
+}
+
+/// The decimal-point character for the current `LC_NUMERIC` locale.
+///
+/// This is `.` in the "C" locale, and in most locales; some, like `de_DE`,
+/// use `,` instead.
+pub fn decimal_point() -> char {
+    #[cfg(unix)]
+    {
+        init();
+        unsafe { lconv_char((*libc::localeconv()).decimal_point).unwrap_or('.') }
+    }
+    #[cfg(not(unix))]
+    {
+        '.'
+    }
+}
+
+/// The thousands-grouping separator for the current `LC_NUMERIC` locale, or
+/// `None` if the locale doesn't group digits (as in the "C" locale).
+pub fn thousands_sep() -> Option<char> {
+    #[cfg(unix)]
+    {
+        init();
+        unsafe { lconv_char((*libc::localeconv()).thousands_sep) }
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+/// The number of digits per group for the current `LC_NUMERIC` locale, or
+/// `None` if the locale doesn't group digits.
+///
+/// Some locales specify a different size for the first group than for the
+/// following ones (POSIX allows a `grouping` string like `"\x03\x02"` to
+/// group as `1,00,00,000`); this only reads the first size and applies it
+/// throughout, which covers every locale actually shipped by glibc.
+pub fn group_size() -> Option<usize> {
+    #[cfg(unix)]
+    {
+        init();
+        unsafe {
+            let grouping = (*libc::localeconv()).grouping;
+            if grouping.is_null() {
+                return None;
+            }
+            // An empty `grouping` string (its first byte being the NUL
+            // terminator, as in the "C" locale) means "don't group digits
+            // at all". `CHAR_MAX` (127) is POSIX's sentinel for "no further
+            // grouping past this point" when it appears later in the
+            // string; either way there's no group size to report here.
+            match *grouping as u8 {
+                0 | 127 => None,
+                n => Some(n as usize),
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+/// Compare two strings the way the current `LC_COLLATE` locale would sort
+/// them, falling back to a plain byte-wise comparison in the "C" locale, on
+/// non-Unix platforms, or if either string contains a NUL byte (`strcoll`
+/// operates on NUL-terminated C strings).
+pub fn collate_compare(a: &str, b: &str) -> Ordering {
+    #[cfg(unix)]
+    {
+        init();
+        if let (Ok(a), Ok(b)) = (CString::new(a), CString::new(b)) {
+            let result = unsafe { libc::strcoll(a.as_ptr(), b.as_ptr()) };
+            return result.cmp(&0);
+        }
+    }
+    a.cmp(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c_locale_defaults() {
+        // The test harness doesn't set any LC_* environment variables, so
+        // this exercises the plain "C" locale behavior.
+        assert_eq!(decimal_point(), '.');
+        assert_eq!(thousands_sep(), None);
+        assert_eq!(group_size(), None);
+    }
+
+    #[test]
+    fn collate_matches_byte_order_in_c_locale() {
+        assert_eq!(collate_compare("abc", "abd"), Ordering::Less);
+        assert_eq!(collate_compare("abc", "abc"), Ordering::Equal);
+        assert_eq!(collate_compare("b", "a"), Ordering::Greater);
+    }
+}
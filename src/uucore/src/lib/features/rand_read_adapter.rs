@@ -12,6 +12,8 @@
 // except according to those terms.
 
 //! A wrapper around any Read to treat it as an RNG.
+//!
+//! Shared by `shred` and `shuf`, both of which support `--random-source=FILE`.
 
 use std::fmt;
 use std::io::Read;
@@ -82,7 +82,10 @@ impl Digest for Blake3 {
     }
 
     fn hash_update(&mut self, input: &[u8]) {
-        self.0.update(input);
+        // Use the Rayon-parallelized update so large files are hashed
+        // across multiple threads; for small inputs blake3 falls back to
+        // single-threaded hashing on its own.
+        self.0.update_rayon(input);
     }
 
     fn hash_finalize(&mut self, out: &mut [u8]) {
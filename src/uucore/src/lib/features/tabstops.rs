@@ -0,0 +1,250 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore (ToDO) nums
+
+//! Parsing and application of `--tabs`-style tab stop lists.
+//!
+//! `expand` and `unexpand` both accept a comma- (or space-) separated list
+//! of tab stops, optionally ending in a `/N` or `+N` specifier describing
+//! how to keep generating tab stops past the end of the explicit list.
+//! This module is shared between the two so that `expand -t LIST | unexpand
+//! -t LIST` round-trips.
+
+use std::error::Error;
+use std::fmt;
+use std::num::IntErrorKind;
+use std::str::from_utf8;
+
+use crate::display::Quotable;
+use crate::error::UError;
+
+static DEFAULT_TABSTOP: usize = 8;
+
+/// The mode to use when computing tab stops beyond the last one specified
+/// in a `--tabs` argument.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RemainingMode {
+    None,
+    Slash,
+    Plus,
+}
+
+/// Errors that can occur when parsing a `--tabs` argument.
+#[derive(Debug)]
+pub enum TabStopsParseError {
+    InvalidCharacter(String),
+    SpecifierNotAtStartOfNumber(String, String),
+    SpecifierOnlyAllowedWithLastValue(String),
+    TabSizeCannotBeZero,
+    TabSizeTooLarge(String),
+    TabSizesMustBeAscending,
+}
+
+impl Error for TabStopsParseError {}
+impl UError for TabStopsParseError {}
+
+impl fmt::Display for TabStopsParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter(s) => {
+                write!(f, "tab size contains invalid character(s): {}", s.quote())
+            }
+            Self::SpecifierNotAtStartOfNumber(specifier, s) => write!(
+                f,
+                "{} specifier not at start of number: {}",
+                specifier.quote(),
+                s.quote(),
+            ),
+            Self::SpecifierOnlyAllowedWithLastValue(specifier) => write!(
+                f,
+                "{} specifier only allowed with the last value",
+                specifier.quote()
+            ),
+            Self::TabSizeCannotBeZero => write!(f, "tab size cannot be 0"),
+            Self::TabSizeTooLarge(s) => write!(f, "tab stop is too large {}", s.quote()),
+            Self::TabSizesMustBeAscending => write!(f, "tab sizes must be ascending"),
+        }
+    }
+}
+
+/// Decide whether the character is either a space or a comma.
+fn is_space_or_comma(c: char) -> bool {
+    c == ' ' || c == ','
+}
+
+/// Parse a list of tabstops from a `--tabs` argument.
+///
+/// This function returns both the vector of numbers appearing in the
+/// comma- or space-separated list, and also a [`RemainingMode`], specified
+/// by either a "/" or a "+" character appearing before the final number
+/// in the list. This mode defines the strategy to use for computing the
+/// number of spaces to use for columns beyond the end of the tab stop
+/// list specified here.
+pub fn parse_tabstops(s: &str) -> Result<(RemainingMode, Vec<usize>), TabStopsParseError> {
+    // Leading commas and spaces are ignored.
+    let s = s.trim_start_matches(is_space_or_comma);
+
+    // If there were only commas and spaces in the string, just use the
+    // default tabstops.
+    if s.is_empty() {
+        return Ok((RemainingMode::None, vec![DEFAULT_TABSTOP]));
+    }
+
+    let mut nums = vec![];
+    let mut remaining_mode = RemainingMode::None;
+    let mut is_specifier_already_used = false;
+    for word in s.split(is_space_or_comma) {
+        let bytes = word.as_bytes();
+        for i in 0..bytes.len() {
+            match bytes[i] {
+                b'+' => remaining_mode = RemainingMode::Plus,
+                b'/' => remaining_mode = RemainingMode::Slash,
+                _ => {
+                    // Parse a number from the byte sequence.
+                    let s = from_utf8(&bytes[i..]).unwrap();
+                    match s.parse::<usize>() {
+                        Ok(num) => {
+                            // Tab size must be positive.
+                            if num == 0 {
+                                return Err(TabStopsParseError::TabSizeCannotBeZero);
+                            }
+
+                            // Tab sizes must be ascending.
+                            if let Some(last_stop) = nums.last() {
+                                if *last_stop >= num {
+                                    return Err(TabStopsParseError::TabSizesMustBeAscending);
+                                }
+                            }
+
+                            if is_specifier_already_used {
+                                let specifier = if remaining_mode == RemainingMode::Slash {
+                                    "/".to_string()
+                                } else {
+                                    "+".to_string()
+                                };
+                                return Err(TabStopsParseError::SpecifierOnlyAllowedWithLastValue(
+                                    specifier,
+                                ));
+                            } else if remaining_mode != RemainingMode::None {
+                                is_specifier_already_used = true;
+                            }
+
+                            // Append this tab stop to the list of all tabstops.
+                            nums.push(num);
+                            break;
+                        }
+                        Err(e) => {
+                            if *e.kind() == IntErrorKind::PosOverflow {
+                                return Err(TabStopsParseError::TabSizeTooLarge(s.to_string()));
+                            }
+
+                            let s = s.trim_start_matches(char::is_numeric);
+                            if s.starts_with('/') || s.starts_with('+') {
+                                return Err(TabStopsParseError::SpecifierNotAtStartOfNumber(
+                                    s[0..1].to_string(),
+                                    s.to_string(),
+                                ));
+                            } else {
+                                return Err(TabStopsParseError::InvalidCharacter(s.to_string()));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // If no numbers could be parsed (for example, if `s` were "+,+,+"),
+    // then just use the default tabstops.
+    if nums.is_empty() {
+        nums = vec![DEFAULT_TABSTOP];
+    }
+
+    if nums.len() < 2 {
+        remaining_mode = RemainingMode::None;
+    }
+    Ok((remaining_mode, nums))
+}
+
+/// Compute the number of columns to the next tabstop.
+///
+/// `tabstops` is the sequence of tabstop locations, `col` is the index of
+/// the current cursor, and `remaining_mode` describes how to keep
+/// generating tab stops past the end of the explicit `tabstops` list.
+///
+/// Returns `None` when `col` is past the last explicit tabstop and
+/// `remaining_mode` is [`RemainingMode::None`], meaning there is no further
+/// tabstop to align to.
+pub fn next_tabstop(tabstops: &[usize], col: usize, remaining_mode: RemainingMode) -> Option<usize> {
+    let num_tabstops = tabstops.len();
+    match remaining_mode {
+        RemainingMode::Plus => match tabstops[0..num_tabstops - 1].iter().find(|&&t| t > col) {
+            Some(t) => Some(t - col),
+            None => {
+                let step_size = tabstops[num_tabstops - 1];
+                let last_fixed_tabstop = tabstops[num_tabstops - 2];
+                let characters_since_last_tabstop = col - last_fixed_tabstop;
+
+                let steps_required = 1 + characters_since_last_tabstop / step_size;
+                Some(steps_required * step_size - characters_since_last_tabstop)
+            }
+        },
+        RemainingMode::Slash => match tabstops[0..num_tabstops - 1].iter().find(|&&t| t > col) {
+            Some(t) => Some(t - col),
+            None => Some(tabstops[num_tabstops - 1] - col % tabstops[num_tabstops - 1]),
+        },
+        RemainingMode::None => {
+            if num_tabstops == 1 {
+                Some(tabstops[0] - col % tabstops[0])
+            } else {
+                tabstops.iter().find(|&&t| t > col).map(|t| t - col)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tabstops_default() {
+        assert_eq!(parse_tabstops("").unwrap(), (RemainingMode::None, vec![8]));
+    }
+
+    #[test]
+    fn test_parse_tabstops_slash_and_plus() {
+        assert_eq!(
+            parse_tabstops("1,/5").unwrap(),
+            (RemainingMode::Slash, vec![1, 5])
+        );
+        assert_eq!(
+            parse_tabstops("1,+5").unwrap(),
+            (RemainingMode::Plus, vec![1, 5])
+        );
+    }
+
+    #[test]
+    fn test_next_tabstop_remaining_mode_none() {
+        assert_eq!(next_tabstop(&[1, 5], 0, RemainingMode::None), Some(1));
+        assert_eq!(next_tabstop(&[1, 5], 3, RemainingMode::None), Some(2));
+        // past the end of the explicit list, there is no further tabstop
+        assert_eq!(next_tabstop(&[1, 5], 6, RemainingMode::None), None);
+    }
+
+    #[test]
+    fn test_next_tabstop_remaining_mode_plus() {
+        assert_eq!(next_tabstop(&[1, 5], 0, RemainingMode::Plus), Some(1));
+        assert_eq!(next_tabstop(&[1, 5], 3, RemainingMode::Plus), Some(3));
+        assert_eq!(next_tabstop(&[1, 5], 6, RemainingMode::Plus), Some(5));
+    }
+
+    #[test]
+    fn test_next_tabstop_remaining_mode_slash() {
+        assert_eq!(next_tabstop(&[1, 5], 0, RemainingMode::Slash), Some(1));
+        assert_eq!(next_tabstop(&[1, 5], 3, RemainingMode::Slash), Some(2));
+        assert_eq!(next_tabstop(&[1, 5], 6, RemainingMode::Slash), Some(4));
+    }
+}
@@ -350,3 +350,30 @@ impl Drop for UtmpxIter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn chars2string_stops_at_first_nul() {
+        let arr: [libc::c_char; 6] = [
+            b'a' as libc::c_char,
+            b'b' as libc::c_char,
+            0,
+            b'c' as libc::c_char,
+            0,
+            0,
+        ];
+        assert_eq!(chars2string!(arr), "ab");
+    }
+
+    #[test]
+    fn chars2string_uses_the_whole_array_when_not_nul_terminated() {
+        let arr: [libc::c_char; 4] = [
+            b'a' as libc::c_char,
+            b'b' as libc::c_char,
+            b'c' as libc::c_char,
+            b'd' as libc::c_char,
+        ];
+        assert_eq!(chars2string!(arr), "abcd");
+    }
+}
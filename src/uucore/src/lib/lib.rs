@@ -45,16 +45,25 @@ pub use crate::features::encoding;
 pub use crate::features::format;
 #[cfg(feature = "fs")]
 pub use crate::features::fs;
+#[cfg(feature = "json")]
+pub use crate::features::json;
 #[cfg(feature = "lines")]
 pub use crate::features::lines;
+#[cfg(feature = "locale")]
+pub use crate::features::locale;
 #[cfg(feature = "quoting-style")]
 pub use crate::features::quoting_style;
+#[cfg(feature = "rand-read-adapter")]
+pub use crate::features::rand_read_adapter;
 #[cfg(feature = "ranges")]
 pub use crate::features::ranges;
 #[cfg(feature = "ringbuffer")]
 pub use crate::features::ringbuffer;
+pub use crate::features::selinux;
 #[cfg(feature = "sum")]
 pub use crate::features::sum;
+#[cfg(feature = "tabstops")]
+pub use crate::features::tabstops;
 #[cfg(feature = "update-control")]
 pub use crate::features::update_control;
 #[cfg(feature = "version-cmp")]
@@ -265,6 +265,18 @@ pub trait UError: Error + Send {
     fn usage(&self) -> bool {
         false
     }
+
+    /// Whether this error represents a broken pipe (`SIGPIPE`/`EPIPE`).
+    ///
+    /// `#[uucore::main]` checks this after `uumain` returns and, if it's
+    /// `true`, exits cleanly without printing anything, instead of treating
+    /// it like any other error. This is what happens when a utility's
+    /// output is piped into something that stops reading early, e.g. `yes |
+    /// head -n1`, and every utility should behave the same way instead of
+    /// each re-implementing its own top-level check.
+    fn is_broken_pipe(&self) -> bool {
+        false
+    }
 }
 
 impl<T> From<T> for Box<dyn UError>
@@ -391,7 +403,11 @@ impl UIoError {
     }
 }
 
-impl UError for UIoError {}
+impl UError for UIoError {
+    fn is_broken_pipe(&self) -> bool {
+        self.inner.kind() == std::io::ErrorKind::BrokenPipe
+    }
+}
 
 impl Error for UIoError {}
 
@@ -767,4 +783,18 @@ mod tests {
                 .to_string()
         );
     }
+
+    #[test]
+    fn test_is_broken_pipe() {
+        use super::{FromIo, UError};
+        use std::io::{Error, ErrorKind};
+
+        let broken_pipe: Box<dyn UError> = Error::from(ErrorKind::BrokenPipe)
+            .map_err_context(|| String::from("write error"));
+        assert!(broken_pipe.is_broken_pipe());
+
+        let not_broken_pipe: Box<dyn UError> =
+            Error::from(ErrorKind::NotFound).map_err_context(|| String::from("write error"));
+        assert!(!not_broken_pipe.is_broken_pipe());
+    }
 }
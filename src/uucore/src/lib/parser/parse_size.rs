@@ -310,6 +310,32 @@ pub fn parse_size_u128_max(size: &str) -> Result<u128, ParseSizeError> {
     Parser::default().parse_u128_max(size)
 }
 
+/// Parse a chain of `'x'`-separated size expressions and multiply the
+/// results together, e.g. `"2x3"` is `2 * 3` and `"1x2x3"` is `1 * 2 * 3`.
+///
+/// This is the multiplier syntax `dd` accepts for its byte-count operands
+/// like `bs=` and `count=`; `parse_part` parses each individual
+/// `'x'`-separated component, using whatever suffix grammar and error type
+/// the caller needs (`dd` also allows a `'c'`/`'w'`/`'b'` block suffix on
+/// each component, which this function knows nothing about).
+///
+/// Returns `Ok(None)` if the multiplication overflows `u64`, so that the
+/// caller can report it however it likes.
+pub fn parse_size_multiplier_chain<E>(
+    size: &str,
+    mut parse_part: impl FnMut(&str) -> Result<u64, E>,
+) -> Result<Option<u64>, E> {
+    let mut total: u64 = 1;
+    for part in size.split('x') {
+        let num = parse_part(part)?;
+        match total.checked_mul(num) {
+            Some(t) => total = t,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(total))
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum ParseSizeError {
     InvalidSuffix(String), // Suffix
@@ -672,4 +698,14 @@ mod tests {
         assert_eq!(Ok(94722), parse_size_u64("0x17202"));
         assert_eq!(Ok(44251 * 1024), parse_size_u128("0xACDBK"));
     }
+
+    #[test]
+    fn multiplier_chain() {
+        let parse = |s| parse_size_multiplier_chain(s, parse_size_u64);
+        assert_eq!(Ok(Some(123)), parse("123"));
+        assert_eq!(Ok(Some(6)), parse("1x2x3"));
+        assert_eq!(Ok(Some(2 * 1024 * 3)), parse("2Kx3"));
+        assert_eq!(Ok(None), parse("18446744073709551615x2"));
+        assert!(parse("1xfoo").is_err());
+    }
 }
@@ -24,6 +24,10 @@ pub fn main(_args: TokenStream, stream: TokenStream) -> TokenStream {
             let result = uumain(args);
             match result {
                 Ok(()) => uucore::error::get_exit_code(),
+                // A broken pipe (e.g. `seq inf | head -n1`) is not a real
+                // failure: exit cleanly, the same as a successful run,
+                // without printing anything.
+                Err(e) if e.is_broken_pipe() => uucore::error::get_exit_code(),
                 Err(e) => {
                     let s = format!("{}", e);
                     if s != "" {
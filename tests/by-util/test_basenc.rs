@@ -231,6 +231,26 @@ fn test_choose_last_encoding_base2lsbf() {
         .stdout_only("00110110110011100100011001100110\n");
 }
 
+#[test]
+fn test_base16_ignore_garbage() {
+    new_ucmd!()
+        .args(&["--base16", "-d", "--ignore-garbage"])
+        .pipe_in("7@4#6F3!E62653F")
+        .succeeds()
+        .no_stderr()
+        .stdout_only("to>be?");
+}
+
+#[test]
+fn test_base16_wrap() {
+    new_ucmd!()
+        .args(&["--base16", "--wrap=4"])
+        .pipe_in("to>be?")
+        .succeeds()
+        .no_stderr()
+        .stdout_only("746F\n3E62\n653F\n");
+}
+
 #[test]
 fn test_base32_decode_repeated() {
     new_ucmd!()
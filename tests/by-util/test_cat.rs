@@ -55,7 +55,10 @@ fn test_no_options_big_input() {
         80 * 1024,
         96 * 1024,
         112 * 1024,
+        128 * 1024 - 1,
         128 * 1024,
+        128 * 1024 + 1,
+        256 * 1024 + 1,
     ] {
         let data = vec_of_size(n);
         let data2 = data.clone();
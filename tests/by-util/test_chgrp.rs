@@ -55,6 +55,22 @@ fn test_invalid_group() {
         .stderr_is("chgrp: invalid group: '__nosuchgroup__'\n");
 }
 
+#[test]
+fn test_numeric_gid_without_named_group() {
+    // A purely numeric GID is used as-is, even if it doesn't correspond to
+    // any named group in the system's group database.
+    use std::os::unix::fs::MetadataExt;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.touch("file");
+    ucmd.arg("4000000001")
+        .arg("file")
+        .succeeds()
+        .no_stdout()
+        .no_stderr();
+    assert_eq!(at.metadata("file").gid(), 4_000_000_001);
+}
+
 #[test]
 fn test_1() {
     if getegid() != 0 {
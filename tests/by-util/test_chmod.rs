@@ -724,3 +724,98 @@ fn test_gnu_special_options() {
     scene.ucmd().arg("--").arg("--").arg("file").succeeds();
     scene.ucmd().arg("--").arg("--").fails();
 }
+
+#[test]
+fn test_chmod_recursive_symlink_default_does_not_traverse() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.mkdir("real");
+    at.make_file("real/file");
+    at.symlink_dir("real", "link");
+
+    scene.ucmd().arg("-R").arg("700").arg("link").succeeds();
+
+    // The symlink argument itself is dereferenced (changing "real"'s mode),
+    // but -P (the default) means we don't descend through it.
+    assert_eq!(at.metadata("real").permissions().mode(), 0o40700);
+    assert_eq!(at.metadata("real/file").permissions().mode(), 0o100644);
+}
+
+#[test]
+fn test_chmod_recursive_dash_l_traverses_every_dir_symlink() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.mkdir("real");
+    at.make_file("real/file");
+    at.symlink_dir("real", "link");
+
+    scene.ucmd().arg("-RL").arg("700").arg("link").succeeds();
+
+    assert_eq!(at.metadata("real").permissions().mode(), 0o40700);
+    assert_eq!(at.metadata("real/file").permissions().mode(), 0o100700);
+}
+
+#[test]
+fn test_chmod_recursive_dash_h_only_traverses_command_line_arg() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.mkdir("real");
+    at.make_file("real/file");
+    at.mkdir("other");
+    at.make_file("other/file");
+    at.symlink_dir("other", "real/link_to_other");
+    at.symlink_dir("real", "link");
+
+    // -H: "link" is a command line argument, so it's traversed...
+    scene.ucmd().arg("-RH").arg("700").arg("link").succeeds();
+    assert_eq!(at.metadata("real").permissions().mode(), 0o40700);
+    assert_eq!(at.metadata("real/file").permissions().mode(), 0o100700);
+    // ...but the symlink found *inside* the traversal is not.
+    assert_eq!(at.metadata("other").permissions().mode(), 0o40755);
+    assert_eq!(at.metadata("other/file").permissions().mode(), 0o100644);
+}
+
+#[test]
+fn test_chmod_recursive_dash_l_detects_symlink_loop() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.mkdir("a");
+    at.symlink_dir("a", "a/loop");
+
+    scene
+        .ucmd()
+        .arg("-RL")
+        .arg("700")
+        .arg("a")
+        .fails()
+        .stderr_contains("possible symbolic link loop");
+}
+
+#[test]
+fn test_chmod_h_l_p_require_recursive() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.touch("file");
+
+    scene
+        .ucmd()
+        .arg("-H")
+        .arg("700")
+        .arg("file")
+        .fails()
+        .stderr_contains("error");
+    scene
+        .ucmd()
+        .arg("-L")
+        .arg("700")
+        .arg("file")
+        .fails()
+        .stderr_contains("error");
+    scene
+        .ucmd()
+        .arg("-P")
+        .arg("700")
+        .arg("file")
+        .fails()
+        .stderr_contains("error");
+}
@@ -6,8 +6,9 @@
 
 use crate::common::util::TestScenario;
 
-const ALGOS: [&str; 11] = [
+const ALGOS: [&str; 15] = [
     "sysv", "bsd", "crc", "md5", "sha1", "sha224", "sha256", "sha384", "sha512", "blake2b", "sm3",
+    "sha3-256", "sha3-384", "sha3-512", "blake3",
 ];
 
 #[test]
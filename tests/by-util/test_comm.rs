@@ -200,6 +200,14 @@ fn zero_terminated_with_total() {
     }
 }
 
+#[test]
+fn zero_terminated_with_total_and_output_delimiter() {
+    new_ucmd!()
+        .args(&["-z", "--total", "--output-delimiter=X", "a_nul", "b_nul"])
+        .succeeds()
+        .stdout_only("a\0Xb\0XXz\01X1X1Xtotal\0");
+}
+
 #[cfg_attr(not(feature = "test_unimplemented"), ignore)]
 #[test]
 fn check_order() {
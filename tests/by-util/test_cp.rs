@@ -470,6 +470,24 @@ fn test_cp_arg_update_all_then_none() {
     assert_eq!(at.read(new), "new content\n");
 }
 
+#[test]
+fn test_cp_arg_update_none_with_no_clobber() {
+    // `-n` keeps its own "fail loudly" behavior (see
+    // `test_cp_arg_no_clobber`) regardless of `--update=none`; the two
+    // options are independent and combining them shouldn't panic or corrupt
+    // the destination.
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    ucmd.arg(TEST_HELLO_WORLD_SOURCE)
+        .arg(TEST_HOW_ARE_YOU_SOURCE)
+        .arg("--update=none")
+        .arg("-n")
+        .fails()
+        .stderr_contains("not replacing");
+
+    assert_eq!(at.read(TEST_HOW_ARE_YOU_SOURCE), "How are you?\n");
+}
+
 #[test]
 fn test_cp_arg_interactive() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -1462,6 +1480,36 @@ fn test_cp_preserve_xattr() {
     }
 }
 
+#[test]
+#[cfg(all(unix, not(target_os = "android")))]
+fn test_cp_preserve_xattr_copies_values() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let src_file = "a";
+    let dst_file = "b";
+
+    at.touch(src_file);
+    let src_path = at.plus(src_file);
+    match xattr::set(&src_path, "user.uutils.test", b"cp-xattr-value") {
+        Ok(()) => {}
+        Err(_) => {
+            // The underlying filesystem doesn't support user xattrs (e.g. tmpfs
+            // mounted without user_xattr); nothing to test here.
+            return;
+        }
+    }
+
+    ucmd.arg(src_file)
+        .arg(dst_file)
+        .arg("--preserve=xattr")
+        .succeeds();
+
+    assert!(compare_xattrs(at.plus(src_file), at.plus(dst_file)));
+    assert_eq!(
+        xattr::get(at.plus(dst_file), "user.uutils.test").unwrap(),
+        Some(b"cp-xattr-value".to_vec())
+    );
+}
+
 #[test]
 #[cfg(all(target_os = "linux", not(feature = "feat_selinux")))]
 fn test_cp_preserve_all_context_fails_on_non_selinux() {
@@ -1682,6 +1730,34 @@ fn test_cp_preserve_links_case_7() {
     assert!(at.plus("dest").join("g").exists());
 }
 
+#[test]
+// android platform will causing stderr = cp: Permission denied (os error 13)
+#[cfg(not(target_os = "android"))]
+fn test_cp_archive_mode_preserves_hard_links() {
+    // `-a` implies `--preserve=links`, so hard links inside the source tree
+    // should be recreated as hard links in the destination rather than
+    // duplicated, keeping a hard-link-heavy backup from ballooning in size.
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    at.mkdir("src");
+    at.write("src/f", "hello");
+    at.hard_link("src/f", "src/g");
+
+    ucmd.arg("-a").arg("src").arg("dest").succeeds();
+
+    assert!(at.dir_exists("dest"));
+    assert!(at.plus("dest").join("f").exists());
+    assert!(at.plus("dest").join("g").exists());
+
+    #[cfg(unix)]
+    {
+        let metadata_f = std::fs::metadata(at.subdir.join("dest").join("f")).unwrap();
+        let metadata_g = std::fs::metadata(at.subdir.join("dest").join("g")).unwrap();
+        assert_eq!(metadata_f.ino(), metadata_g.ino());
+        assert_eq!(metadata_f.nlink(), 2);
+    }
+}
+
 #[test]
 #[cfg(unix)]
 fn test_cp_no_preserve_mode() {
@@ -2175,6 +2251,24 @@ fn test_cp_reflink_auto() {
     assert_eq!(at.read(TEST_EXISTING_FILE), "Hello, World!\n");
 }
 
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn test_cp_reflink_auto_large_file() {
+    // Exercises the accelerated (copy_file_range/clone) code path rather than
+    // the tiny-file fast path, to catch corruption in large same-filesystem
+    // copies (e.g. VM disk images).
+    let (at, mut ucmd) = at_and_ucmd!();
+    let content = vec![0xab_u8; 5 * 1024 * 1024];
+    at.write_bytes("big-source", &content);
+
+    ucmd.arg("--reflink=auto")
+        .arg("big-source")
+        .arg("big-dest")
+        .succeeds();
+
+    assert_eq!(at.read_bytes("big-dest"), content);
+}
+
 #[test]
 #[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
 fn test_cp_reflink_none() {
@@ -3242,6 +3336,31 @@ fn test_reflink_never_sparse_always() {
     assert_eq!(dest_metadata.len(), 1024 * 1024);
 }
 
+#[cfg(target_os = "linux")]
+#[test]
+fn test_reflink_never_sparse_auto() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    // `--sparse=auto` is the default, but it should still recreate the
+    // holes that are already present in `src`, rather than only avoiding
+    // *new* ones (that's what `--sparse=never` would do).
+    std::fs::File::create(at.plus("src"))
+        .unwrap()
+        .set_len(1024 * 1024)
+        .unwrap();
+
+    ucmd.args(&["--reflink=never", "--sparse=auto", "src", "dest"])
+        .succeeds()
+        .no_stdout()
+        .no_stderr();
+    at.file_exists("dest");
+
+    let src_metadata = std::fs::metadata(at.plus("src")).unwrap();
+    let dest_metadata = std::fs::metadata(at.plus("dest")).unwrap();
+    assert_eq!(src_metadata.blocks(), dest_metadata.blocks());
+    assert_eq!(dest_metadata.len(), 1024 * 1024);
+}
+
 /// Test for preserving attributes of a hard link in a directory.
 #[test]
 #[cfg(not(target_os = "android"))]
@@ -3390,7 +3509,7 @@ fn test_cp_debug_default() {
         panic!("Failure: stdout was \n{stdout_str}");
     }
     #[cfg(target_os = "linux")]
-    if !stdout_str.contains("copy offload: unknown, reflink: unsupported, sparse detection: no") {
+    if !stdout_str.contains("copy offload: unknown, reflink: no, sparse detection: SEEK_HOLE") {
         panic!("Failure: stdout was \n{stdout_str}");
     }
 
@@ -3443,7 +3562,8 @@ fn test_cp_debug_multiple_default() {
 
     #[cfg(target_os = "linux")]
     {
-        if !stdout_str.contains("copy offload: unknown, reflink: unsupported, sparse detection: no")
+        if !stdout_str
+            .contains("copy offload: unknown, reflink: no, sparse detection: SEEK_HOLE")
         {
             panic!("Failure: stdout was \n{stdout_str}");
         }
@@ -3452,7 +3572,7 @@ fn test_cp_debug_multiple_default() {
         assert_eq!(
             result
                 .stdout_str()
-                .matches("copy offload: unknown, reflink: unsupported, sparse detection: no")
+                .matches("copy offload: unknown, reflink: no, sparse detection: SEEK_HOLE")
                 .count(),
             2
         );
@@ -3586,7 +3706,8 @@ fn test_cp_debug_sparse_auto() {
         }
 
         #[cfg(target_os = "linux")]
-        if !stdout_str.contains("copy offload: unknown, reflink: unsupported, sparse detection: no")
+        if !stdout_str
+            .contains("copy offload: unknown, reflink: no, sparse detection: SEEK_HOLE")
         {
             panic!("Failure: stdout was \n{stdout_str}");
         }
@@ -3610,7 +3731,8 @@ fn test_cp_debug_reflink_auto() {
     #[cfg(target_os = "linux")]
     {
         let stdout_str = result.stdout_str();
-        if !stdout_str.contains("copy offload: unknown, reflink: unsupported, sparse detection: no")
+        if !stdout_str
+            .contains("copy offload: unknown, reflink: no, sparse detection: SEEK_HOLE")
         {
             panic!("Failure: stdout was \n{stdout_str}");
         }
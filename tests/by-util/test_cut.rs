@@ -307,6 +307,33 @@ fn test_no_argument() {
     );
 }
 
+#[test]
+fn test_multibyte_utf8_delimiter() {
+    new_ucmd!()
+        .args(&["-d", "é", "-f2"])
+        .pipe_in("aéb\ncéd\n")
+        .succeeds()
+        .stdout_is("b\nd\n");
+}
+
+#[test]
+fn test_multibyte_utf8_output_delimiter() {
+    new_ucmd!()
+        .args(&["-d,", "--output-delimiter=é", "-f1,2"])
+        .pipe_in("a,b\n")
+        .succeeds()
+        .stdout_is("aéb\n");
+}
+
+#[test]
+fn test_complement_with_multibyte_delimiter() {
+    new_ucmd!()
+        .args(&["-d", "é", "--complement", "-f2"])
+        .pipe_in("aébéc\n")
+        .succeeds()
+        .stdout_is("aéc\n");
+}
+
 #[test]
 #[cfg(unix)]
 fn test_8bit_non_utf8_delimiter() {
@@ -391,6 +391,67 @@ fn test_date_string_human() {
     }
 }
 
+#[test]
+fn test_date_string_weekday() {
+    // Every weekday name should at least parse, whatever day "today" is.
+    let re = Regex::new(r"^\d{4}-\d{2}-\d{2}\n$").unwrap();
+    for date_format in [
+        "monday",
+        "next monday",
+        "last monday",
+        "this monday",
+        "friday",
+        "next friday",
+        "last friday",
+    ] {
+        new_ucmd!()
+            .arg("-d")
+            .arg(date_format)
+            .arg("+%Y-%m-%d")
+            .succeeds()
+            .stdout_matches(&re);
+    }
+}
+
+#[test]
+fn test_date_string_weekday_next_is_strictly_after_today() {
+    // "next <today's weekday>" must land a full week later, not today.
+    let today = chrono::Local::now().date_naive();
+    let weekday_name = today.format("%A").to_string().to_lowercase();
+
+    let result = new_ucmd!()
+        .arg("-d")
+        .arg(format!("next {weekday_name}"))
+        .arg("+%Y-%m-%d")
+        .succeeds();
+    let parsed = chrono::NaiveDate::parse_from_str(result.stdout_str().trim(), "%Y-%m-%d")
+        .expect("date output should be parseable");
+
+    assert_eq!(parsed - today, chrono::TimeDelta::try_days(7).unwrap());
+}
+
+#[test]
+fn test_date_string_weekday_plus_offset() {
+    let re = Regex::new(r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}\n$").unwrap();
+    new_ucmd!()
+        .arg("-d")
+        .arg("next friday + 3 hours")
+        .arg("+%Y-%m-%d %H:%M")
+        .succeeds()
+        .stdout_matches(&re);
+}
+
+#[test]
+fn test_date_string_compound_offset() {
+    let re = Regex::new(r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}\n$").unwrap();
+    new_ucmd!()
+        .arg("-d")
+        .arg("last month + 3 hours")
+        .arg("+%Y-%m-%d %H:%M")
+        .succeeds()
+        .stdout_matches(&re);
+}
+
 #[test]
 fn test_invalid_date_string() {
     new_ucmd!()
@@ -401,6 +462,17 @@ fn test_invalid_date_string() {
         .stderr_contains("invalid date");
 }
 
+#[test]
+fn test_date_resolution() {
+    // `--resolution` just prints the clock resolution as decimal seconds,
+    // e.g. "0.000000001" for a one-nanosecond clock.
+    let re = Regex::new(r"^\d+\.\d{9}\n$").unwrap();
+    new_ucmd!()
+        .arg("--resolution")
+        .succeeds()
+        .stdout_matches(&re);
+}
+
 #[test]
 fn test_date_overflow() {
     new_ucmd!()
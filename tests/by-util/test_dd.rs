@@ -283,6 +283,104 @@ fn test_final_stats_unspec() {
         .success();
 }
 
+#[test]
+fn test_final_stats_progress() {
+    new_ucmd!()
+        .args(&["status=progress"])
+        .run()
+        .stderr_contains("0+0 records in\n0+0 records out\n0 bytes copied, ")
+        .stderr_matches(&Regex::new(r"\d(\.\d+)?(e-\d\d)? s, ").unwrap())
+        .stderr_contains("0.0 B/s")
+        .success();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_sigusr1_reports_progress() {
+    use nix::{sys::signal, unistd::Pid};
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("sigusr1-input.txt", &"a".repeat(1024 * 1024));
+
+    let mut child = ucmd
+        .args(&[
+            "status=none",
+            "if=sigusr1-input.txt",
+            "of=/dev/null",
+            "bs=1",
+        ])
+        .run_no_wait();
+
+    // Give `dd` a moment to start its main loop before signalling it.
+    sleep(Duration::from_millis(200));
+    signal::kill(Pid::from_raw(child.id() as i32), signal::SIGUSR1).unwrap();
+
+    child
+        .wait()
+        .unwrap()
+        .stderr_contains("records in")
+        .stderr_contains(" bytes (");
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_iflag_direct() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let data = "a".repeat(1024 * 1024);
+    at.write("direct-input.txt", &data);
+
+    ucmd.args(&[
+        "iflag=direct",
+        "if=direct-input.txt",
+        "of=direct-output.txt",
+        "bs=4096",
+        "status=none",
+    ])
+    .succeeds();
+
+    assert_eq!(at.read("direct-output.txt"), data);
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_oflag_direct() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let data = "a".repeat(1024 * 1024);
+    at.write("direct-input.txt", &data);
+
+    ucmd.args(&[
+        "oflag=direct",
+        "if=direct-input.txt",
+        "of=direct-output.txt",
+        "bs=4096",
+        "status=none",
+    ])
+    .succeeds();
+
+    assert_eq!(at.read("direct-output.txt"), data);
+}
+
+/// `oflag=direct` on a file whose size isn't a multiple of `bs` still
+/// succeeds by falling back to buffered I/O for the final short write.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_oflag_direct_partial_final_block() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let data = "a".repeat(1024 * 1024 + 37);
+    at.write("direct-input.txt", &data);
+
+    ucmd.args(&[
+        "oflag=direct",
+        "if=direct-input.txt",
+        "of=direct-output.txt",
+        "bs=4096",
+        "status=none",
+    ])
+    .succeeds();
+
+    assert_eq!(at.read("direct-output.txt"), data);
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 #[test]
 fn test_excl_causes_failure_when_present() {
@@ -1493,6 +1591,16 @@ fn test_sparse() {
     // The number of bytes in the file should be accurate though the
     // number of blocks stored on disk may be zero.
     assert_eq!(at.metadata("infile").len(), at.metadata("outfile").len());
+
+    // The all-zero blocks should have been seeked over rather than
+    // actually written, so the output file should use far fewer disk
+    // blocks than its apparent length would suggest.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let blocks_on_disk = at.metadata("outfile").blocks() * 512;
+        assert!(blocks_on_disk < at.metadata("outfile").len());
+    }
 }
 
 /// Test that a seek on an output FIFO results in a read.
@@ -80,6 +80,16 @@ fn test_df_output_arg() {
         .fails();
 }
 
+#[test]
+fn test_df_output_arg_duplicate_field_in_one_list() {
+    // A field repeated within a single comma-separated --output=... list is
+    // just as much an error as repeating it across separate --output flags.
+    new_ucmd!()
+        .args(&["--output=source,source"])
+        .fails()
+        .stderr_contains("field 'source' used more than once");
+}
+
 #[test]
 fn test_df_output() {
     let expected = if cfg!(target_os = "macos") {
@@ -294,6 +304,18 @@ fn test_type_option_with_file() {
     }
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_type_option_shows_dummy_filesystem_with_all() {
+    // `-t TYPE` alone still hides dummy/pseudo filesystems like `proc`; `-a`
+    // must override that, same as it does without `-t`.
+    new_ucmd!()
+        .args(&["-t", "proc"])
+        .fails()
+        .stderr_contains("no file systems processed");
+    new_ucmd!().args(&["-a", "-t", "proc"]).succeeds();
+}
+
 #[test]
 fn test_exclude_type_option() {
     new_ucmd!().args(&["-x", "ext4", "-x", "ext3"]).succeeds();
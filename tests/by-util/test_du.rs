@@ -710,6 +710,18 @@ fn test_du_invalid_threshold() {
     ts.ucmd().arg(format!("--threshold={threshold}")).fails();
 }
 
+#[test]
+fn test_du_threshold_zero() {
+    // Unlike "-0", a plain "0" threshold is valid: everything is at least
+    // as large as zero, so nothing gets filtered out.
+    let ts = TestScenario::new(util_name!());
+    ts.ucmd()
+        .arg("--threshold=0")
+        .succeeds()
+        .stdout_contains("links")
+        .stdout_contains("deeper_dir");
+}
+
 #[test]
 fn test_du_apparent_size() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -766,6 +778,34 @@ fn test_du_bytes() {
     }
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_du_json() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    at.mkdir_all("a/b");
+    at.write("a/b/file1", "foo");
+
+    let result = ucmd.args(&["--bytes", "--all", "--json", "a"]).succeeds();
+
+    result.stdout_contains(r#"{"path":"a/b/file1","size":3}"#);
+    result.stdout_contains(r#"{"path":"a/b","size":3}"#);
+    result.stdout_contains(r#"{"path":"a","size":3}"#);
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_du_json_total() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    at.mkdir_all("a");
+    at.write("a/file1", "foo");
+
+    let result = ucmd.args(&["--bytes", "--json", "--total", "a"]).succeeds();
+
+    result.stdout_contains(r#"{"path":null,"size":3}"#);
+}
+
 #[test]
 fn test_du_exclude() {
     let ts = TestScenario::new(util_name!());
@@ -419,6 +419,21 @@ fn test_split_string_into_args_s_whitespace_handling() {
     assert_eq!(out, "xAx\nxBx\n");
 }
 
+#[cfg(not(target_os = "windows"))] // windows has no executable "echo"
+#[test]
+fn test_split_string_shebang_style_interpreter_flags() {
+    // Mirrors the canonical GNU-documented shebang usage:
+    // `#!/usr/bin/env -S interpreter --flag`.
+    let scene = TestScenario::new(util_name!());
+
+    let out = scene
+        .ucmd()
+        .arg("-Secho -w -T # this flag combo is what perl shebangs commonly use")
+        .succeeds()
+        .stdout_move_str();
+    assert_eq!(out, "-w -T\n");
+}
+
 #[cfg(not(target_os = "windows"))] // no printf available
 #[test]
 fn test_split_string_into_args_long_option_whitespace_handling() {
@@ -793,6 +808,105 @@ fn test_env_arg_argv0_overwrite_mixed_with_string_args() {
         .stderr_is("");
 }
 
+#[test]
+#[cfg(unix)]
+fn test_env_ignore_signal() {
+    let ts = TestScenario::new(util_name!());
+
+    ts.ucmd()
+        .args(&["--ignore-signal=INT", "sh", "-c", "kill -INT $$; echo alive"])
+        .succeeds()
+        .stdout_is("alive\n");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_env_ignore_signal_comma_list_and_repeated() {
+    let ts = TestScenario::new(util_name!());
+
+    ts.ucmd()
+        .args(&[
+            "--ignore-signal=INT,TERM",
+            "--ignore-signal=HUP",
+            "sh",
+            "-c",
+            "kill -INT $$; kill -TERM $$; kill -HUP $$; echo alive",
+        ])
+        .succeeds()
+        .stdout_is("alive\n");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_env_ignore_signal_without_sig_ignores_all() {
+    let ts = TestScenario::new(util_name!());
+
+    // with no SIG given, every signal is ignored (except SIGCHLD, which env
+    // itself relies on to learn the command's exit status)
+    ts.ucmd()
+        .args(&["--ignore-signal", "sh", "-c", "kill -INT $$; echo alive"])
+        .succeeds()
+        .stdout_is("alive\n");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_env_default_signal_keeps_default_handling() {
+    let ts = TestScenario::new(util_name!());
+
+    ts.ucmd()
+        .args(&[
+            "--default-signal=INT",
+            "sh",
+            "-c",
+            "kill -INT $$; echo unreachable",
+        ])
+        .fails()
+        .no_stdout();
+}
+
+#[test]
+#[cfg(unix)]
+fn test_env_ignore_signal_takes_precedence_over_default_signal() {
+    let ts = TestScenario::new(util_name!());
+
+    // --ignore-signal is applied after --default-signal regardless of
+    // command-line order, so it wins when both name the same signal.
+    ts.ucmd()
+        .args(&[
+            "--default-signal=INT",
+            "--ignore-signal=INT",
+            "sh",
+            "-c",
+            "kill -INT $$; echo alive",
+        ])
+        .succeeds()
+        .stdout_is("alive\n");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_env_block_signal() {
+    let ts = TestScenario::new(util_name!());
+
+    ts.ucmd()
+        .args(&["--block-signal=INT", "sh", "-c", "kill -INT $$; echo alive"])
+        .succeeds()
+        .stdout_is("alive\n");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_env_signal_flag_invalid_signal_name() {
+    let ts = TestScenario::new(util_name!());
+
+    ts.ucmd()
+        .args(&["--ignore-signal=NOTASIGNAL", "true"])
+        .fails()
+        .code_is(125)
+        .stderr_contains("invalid signal");
+}
+
 #[cfg(test)]
 mod tests_split_iterator {
 
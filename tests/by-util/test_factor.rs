@@ -288,6 +288,28 @@ fn test_random_big() {
     run(input_string.as_bytes(), output_string.as_bytes());
 }
 
+#[test]
+fn test_semiprime_two_large_factors() {
+    // The product of two ~32-bit primes is the case Pollard's rho is
+    // actually for: too big to find by trial division, but with no small
+    // factors to strip off first either.
+    let semiprimes: &[(u64, u64, u64)] = &[
+        (4294967279, 4294967291, 18446743979220271189),
+        (3999999979, 4000000007, 15999999943999999853),
+        (2147483647, 2147483659, 4611686039902224373),
+    ];
+
+    let mut input_string = String::new();
+    let mut output_string = String::new();
+    for &(a, b, product) in semiprimes {
+        assert_eq!(a * b, product);
+        input_string.push_str(&(format!("{product} "))[..]);
+        output_string.push_str(&(format!("{product}: {a} {b}\n"))[..]);
+    }
+
+    run(input_string.as_bytes(), output_string.as_bytes());
+}
+
 #[test]
 fn test_big_primes() {
     let mut input_string = String::new();
@@ -159,3 +159,57 @@ fn test_fmt_set_goal_not_contain_width() {
             .stdout_is("this is a file with one word per line\n");
     }
 }
+
+#[test]
+fn test_fmt_tagged_paragraph() {
+    for param in ["-t", "--tagged-paragraph"] {
+        new_ucmd!()
+            .args(&[param, "-w", "30"])
+            .pipe_in(
+                "  Tagged: first line of a tagged paragraph continues on and on and on for a while here okay.\n",
+            )
+            .succeeds()
+            .stdout_is(
+                "  Tagged: first line of a\n      tagged paragraph continues\n      on and on and on for\n      a while here okay.\n",
+            );
+    }
+}
+
+#[test]
+fn test_fmt_crown_margin() {
+    for param in ["-c", "--crown-margin"] {
+        new_ucmd!()
+            .args(&[param, "-w", "30"])
+            .pipe_in(
+                "  Crown margin first line of paragraph continues here with more words okay yes.\n  second and further lines of paragraph keep indent level consistent here yes.\n",
+            )
+            .succeeds()
+            .stdout_is(
+                "  Crown margin first line of\n  paragraph continues here\n  with more words okay yes.\n  second and further lines\n  of paragraph keep indent\n  level consistent here yes.\n",
+            );
+    }
+}
+
+#[test]
+fn test_fmt_prefix() {
+    for param in ["-p", "--prefix"] {
+        new_ucmd!()
+            .args(&[param, "# ", "-w", "20"])
+            .pipe_in("# comment one two three four five six seven eight nine ten\n")
+            .succeeds()
+            .stdout_is(
+                "# comment one two\n# three four five\n# six seven eight\n# nine ten\n",
+            );
+    }
+}
+
+#[test]
+fn test_fmt_cjk_wide_chars() {
+    // Each of these CJK characters should count as a display width of 2,
+    // rather than 1, when deciding where to break lines.
+    new_ucmd!()
+        .args(&["-w", "20"])
+        .pipe_in("一二三四 五六七八 九十十一 十二十三 十四十五 十六十七\n")
+        .succeeds()
+        .stdout_is("一二三四 五六七八\n九十十一 十二十三\n十四十五 十六十七\n");
+}
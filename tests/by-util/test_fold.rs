@@ -185,6 +185,26 @@ fn test_fold_at_word_boundary() {
         .stdout_is("one \ntwo");
 }
 
+#[test]
+fn test_wide_chars_count_as_two_columns() {
+    // Double-width characters (e.g. CJK) occupy two display columns each,
+    // so only half as many of them fit on a line as narrow characters.
+    new_ucmd!()
+        .arg("-w4")
+        .pipe_in("中中中中")
+        .succeeds()
+        .stdout_is("中中\n中中");
+}
+
+#[test]
+fn test_wide_chars_at_word_boundary() {
+    new_ucmd!()
+        .args(&["-w4", "-s"])
+        .pipe_in("中 中 中")
+        .succeeds()
+        .stdout_is("中 \n中 \n中");
+}
+
 #[test]
 fn test_fold_at_leading_word_boundary() {
     new_ucmd!()
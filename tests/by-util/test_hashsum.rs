@@ -208,11 +208,87 @@ fn test_check_file_not_found_warning() {
         .ccmd("sha1sum")
         .arg("-c")
         .arg(at.subdir.join("testf.sha1"))
-        .succeeds()
+        .fails()
+        .code_is(1)
         .stdout_is("sha1sum: testf: No such file or directory\ntestf: FAILED open or read\n")
         .stderr_is("sha1sum: warning: 1 listed file could not be read\n");
 }
 
+#[test]
+fn test_check_ignore_missing() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.write("testf", "foobar\n");
+    at.write(
+        "testf.sha1",
+        "988881adc9fc3655077dc2d4d757d480b5ea0e11  testf\n",
+    );
+    at.remove("testf");
+    scene
+        .ccmd("sha1sum")
+        .arg("--ignore-missing")
+        .arg("-c")
+        .arg(at.subdir.join("testf.sha1"))
+        .fails()
+        .code_is(1)
+        .no_stdout()
+        .stderr_is("sha1sum: warning: no file was verified\n");
+}
+
+#[test]
+fn test_check_ignore_missing_with_match() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.write("testf", "foobar\n");
+    at.write(
+        "testf.sha1",
+        "988881adc9fc3655077dc2d4d757d480b5ea0e11  testf\n\
+         988881adc9fc3655077dc2d4d757d480b5ea0e11  missing\n",
+    );
+    scene
+        .ccmd("sha1sum")
+        .arg("--ignore-missing")
+        .arg("-c")
+        .arg(at.subdir.join("testf.sha1"))
+        .succeeds()
+        .no_stderr()
+        .stdout_is("testf: OK\n");
+}
+
+#[test]
+fn test_ignore_missing_without_check() {
+    let scene = TestScenario::new(util_name!());
+    scene
+        .ccmd("md5sum")
+        .arg("--ignore-missing")
+        .arg("input.txt")
+        .fails()
+        .code_is(1)
+        .stderr_contains("the --ignore-missing option is meaningful only when verifying checksums");
+}
+
+#[test]
+fn test_check_mismatch_exit_code() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.write("testf", "foobar\n");
+    at.write(
+        "testf.sha1",
+        "0000000000000000000000000000000000000000  testf\n",
+    );
+    scene
+        .ccmd("sha1sum")
+        .arg("-c")
+        .arg(at.subdir.join("testf.sha1"))
+        .fails()
+        .code_is(1)
+        .stdout_is("testf: FAILED\n")
+        .stderr_is("sha1sum: warning: 1 computed checksum did NOT match\n");
+}
+
 // Asterisk `*` is a reserved paths character on win32, nor the path can end with a whitespace.
 // ref: https://learn.microsoft.com/en-us/windows/win32/fileio/naming-a-file#naming-conventions
 #[test]
@@ -226,6 +226,17 @@ fn test_file_backwards() {
         .stdout_is_fixture("lorem_ipsum_backwards_file.expected");
 }
 
+#[test]
+fn test_stdin_backwards_bytes() {
+    // `-c -N` must also work on non-seekable input like a pipe, via the
+    // streaming ring-buffer path.
+    new_ucmd!()
+        .args(&["-c", "-10"])
+        .pipe_in_fixture("lorem_ipsum.txt")
+        .run()
+        .stdout_is_fixture("lorem_ipsum_backwards_file.expected");
+}
+
 #[test]
 fn test_zero_terminated() {
     new_ucmd!()
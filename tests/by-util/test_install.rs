@@ -71,11 +71,10 @@ fn test_install_unimplemented_arg() {
     let (at, mut ucmd) = at_and_ucmd!();
     let dir = "target_dir";
     let file = "source_file";
-    let context_arg = "--context";
 
     at.touch(file);
     at.mkdir(dir);
-    ucmd.arg(context_arg)
+    ucmd.arg("--preserve-context")
         .arg(file)
         .arg(dir)
         .fails()
@@ -84,6 +83,24 @@ fn test_install_unimplemented_arg() {
     assert!(!at.file_exists(format!("{dir}/{file}")));
 }
 
+#[test]
+fn test_install_context_without_selinux_support() {
+    // This build has no libselinux available, so `-Z`/`--context` can't
+    // actually label anything; it should fail per-file rather than being
+    // rejected as an unimplemented argument.
+    let (at, mut ucmd) = at_and_ucmd!();
+    let dir = "target_dir";
+    let file = "source_file";
+
+    at.touch(file);
+    at.mkdir(dir);
+    ucmd.arg("--context=unconfined_u:object_r:etc_t:s0")
+        .arg(file)
+        .arg(dir)
+        .fails()
+        .stderr_contains("failed to set");
+}
+
 #[test]
 fn test_install_ancestors_directories() {
     let (at, mut ucmd) = at_and_ucmd!();
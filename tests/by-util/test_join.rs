@@ -327,6 +327,27 @@ fn nocheck_order() {
         .stdout_only_fixture("default.expected");
 }
 
+#[test]
+fn full_outer_join_autoformat_with_empty_fill() {
+    new_ucmd!()
+        .arg("-")
+        .arg("fields_2.txt")
+        .arg("-a")
+        .arg("1")
+        .arg("-a")
+        .arg("2")
+        .arg("-o")
+        .arg("auto")
+        .arg("-e")
+        .arg(".")
+        .pipe_in("1 x y z\n2 p\n99 a b\n")
+        .succeeds()
+        .stdout_only(
+            "1 x y z a\n2 p . . b\n3 . . . c\n4 . . . d\n5 . . . e\n\
+             6 . . . f\n7 . . . g\n8 . . . h\n9 . . . i\n99 a b . .\n",
+        );
+}
+
 #[test]
 fn wrong_line_order() {
     let ts = TestScenario::new(util_name!());
@@ -92,6 +92,17 @@ fn test_kill_list_one_signal_from_name() {
         .stdout_matches(&Regex::new("\\b9\\b").unwrap());
 }
 
+#[test]
+fn test_kill_list_one_signal_from_exit_status() {
+    // A shell reports a process killed by SIGKILL (9) as exited with
+    // status 137 (128 + 9); `kill -l` should accept that form too.
+    new_ucmd!()
+        .arg("-l")
+        .arg("137")
+        .succeeds()
+        .stdout_is("KILL\n");
+}
+
 #[test]
 fn test_kill_list_all_vertically() {
     // Check for a few signals.  Do not try to be comprehensive.
@@ -621,6 +621,23 @@ fn test_relative_recursive() {
     assert_eq!(at.resolve_link("dir/recursive"), ".");
 }
 
+#[cfg(not(windows))]
+#[test]
+fn test_relative_resolves_symlinked_directory_component() {
+    // -r must resolve symlinks in the directory components of both the
+    // source and the link location, not just treat them as plain path
+    // segments, so that the resulting relative path is correct even when
+    // accessed through a symlinked directory.
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir("real_dir");
+    at.touch("real_dir/file");
+    at.symlink_dir("real_dir", "link_dir");
+
+    ucmd.args(&["-sr", "link_dir/file", "result_link"])
+        .succeeds();
+    assert_eq!(at.resolve_link("result_link"), "real_dir/file");
+}
+
 #[test]
 fn test_backup_same_file() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -1043,11 +1043,13 @@ fn test_ls_zero() {
             .stdout_only("0-test-zero\x001?test-zero\x002-test-zero\x003-test-zero\x00");
     }
 
-    scene
-        .ucmd()
-        .args(&["-l", "--zero"])
-        .succeeds()
-        .stdout_contains("total ");
+    let result = scene.ucmd().args(&["-l", "--zero"]).succeeds();
+    let stdout = result.stdout_str();
+    assert!(stdout.starts_with("total "));
+    // The "total" line itself is NUL-terminated too, not newline-terminated.
+    let total_line_end = stdout.find('\0').unwrap();
+    assert!(!stdout[..total_line_end].contains('\n'));
+    assert!(stdout.ends_with('\0'));
 }
 
 #[test]
@@ -1915,6 +1917,39 @@ fn test_ls_styles() {
         .stdout_is("test  test2\n");
 }
 
+#[test]
+fn test_ls_time_style_two_line_format() {
+    // A +FORMAT of "recent\nolder" picks the format based on file recency;
+    // a freshly touched file is always "recent".
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.touch("test");
+
+    let re_recent = Regex::new(r"[a-z-]* \d* [\w.]* [\w.]* \d* RECENT test\n").unwrap();
+    scene
+        .ucmd()
+        .arg("-l")
+        .arg("--time-style=+RECENT\nOLDER")
+        .succeeds()
+        .stdout_matches(&re_recent);
+}
+
+#[test]
+fn test_ls_time_style_env_var() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.touch("test");
+
+    let re_long = Regex::new(r"[a-z-]* \d* [\w.]* [\w.]* \d* \d{4}-\d{2}-\d{2} \d{2}:\d{2} test\n")
+        .unwrap();
+    scene
+        .ucmd()
+        .arg("-l")
+        .env("TIME_STYLE", "long-iso")
+        .succeeds()
+        .stdout_matches(&re_long);
+}
+
 #[test]
 fn test_ls_order_time() {
     let scene = TestScenario::new(util_name!());
@@ -2562,6 +2597,28 @@ fn test_ls_version_sort() {
     );
 }
 
+#[test]
+fn test_ls_version_sort_kernel_style_names() {
+    // Kernel-source-tree-style version suffixes should sort numerically,
+    // not lexicographically, matching `sort -V`.
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    for filename in ["vmlinux-5.4.9", "vmlinux-5.4.10", "vmlinux-5.4.2"] {
+        at.touch(filename);
+    }
+
+    let result = scene.ucmd().arg("-1v").succeeds();
+    assert_eq!(
+        result.stdout_str().split('\n').collect::<Vec<_>>(),
+        vec![
+            "vmlinux-5.4.2",
+            "vmlinux-5.4.9",
+            "vmlinux-5.4.10",
+            "", // trailing newline
+        ]
+    );
+}
+
 #[test]
 fn test_ls_quoting_style() {
     let scene = TestScenario::new(util_name!());
@@ -4249,6 +4306,13 @@ fn test_ls_hyperlink() {
         .arg("--hyperlink=never")
         .succeeds()
         .stdout_is(format!("{file}\n"));
+
+    // Under test, stdout is not a tty, so "auto" should behave like "never".
+    scene
+        .ucmd()
+        .arg("--hyperlink=auto")
+        .succeeds()
+        .stdout_is(format!("{file}\n"));
 }
 
 // spell-checker: disable
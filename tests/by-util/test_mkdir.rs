@@ -286,3 +286,18 @@ fn test_umask_compliance() {
         test_single_case(i as mode_t);
     }
 }
+
+#[test]
+#[cfg(not(feature = "feat_selinux"))]
+fn test_selinux_context_without_selinux_support() {
+    new_ucmd!()
+        .arg("-Z")
+        .arg("test_dir")
+        .fails()
+        .stderr_contains("SELinux is not enabled");
+    new_ucmd!()
+        .arg("--context=unconfined_u:object_r:user_tmp_t:s0")
+        .arg("test_dir")
+        .fails()
+        .stderr_contains("SELinux is not enabled");
+}
@@ -52,3 +52,18 @@ fn test_create_one_fifo_already_exists() {
         .fails()
         .stderr_is("mkfifo: cannot create fifo 'abcdef': File exists\n");
 }
+
+#[test]
+#[cfg(not(feature = "feat_selinux"))]
+fn test_selinux_context_without_selinux_support() {
+    new_ucmd!()
+        .arg("-Z")
+        .arg("abcdefg")
+        .fails()
+        .stderr_contains("SELinux is not enabled");
+    new_ucmd!()
+        .arg("--context=unconfined_u:object_r:user_tmp_t:s0")
+        .arg("abcdefgh")
+        .fails()
+        .stderr_contains("SELinux is not enabled");
+}
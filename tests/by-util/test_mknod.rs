@@ -129,3 +129,20 @@ fn test_mknod_invalid_mode() {
         .code_is(1)
         .stderr_contains("invalid mode");
 }
+
+#[test]
+#[cfg(all(not(windows), not(feature = "feat_selinux")))]
+fn test_selinux_context_without_selinux_support() {
+    new_ucmd!()
+        .arg("-Z")
+        .arg("test_file")
+        .arg("p")
+        .fails()
+        .stderr_contains("SELinux is not enabled");
+    new_ucmd!()
+        .arg("--context=unconfined_u:object_r:user_tmp_t:s0")
+        .arg("test_file")
+        .arg("p")
+        .fails()
+        .stderr_contains("SELinux is not enabled");
+}
@@ -1610,6 +1610,52 @@ fn test_acl() {
     assert!(compare_xattrs(&file, &file_target));
 }
 
+#[test]
+fn test_mv_progress_bar() {
+    // `-g`/`--progress` just adds progress reporting on top of a normal move;
+    // the move itself (including the copy+unlink fallback path used for
+    // cross-filesystem moves) must still produce a byte-identical file.
+    let (at, mut ucmd) = at_and_ucmd!();
+    let content = "a".repeat(1024 * 1024);
+    at.write("source_file", &content);
+
+    ucmd.arg("-g")
+        .arg("source_file")
+        .arg("dest_file")
+        .succeeds();
+
+    assert!(!at.file_exists("source_file"));
+    assert_eq!(at.read("dest_file"), content);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_mv_exchange() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("a", "content of a");
+    at.write("b", "content of b");
+
+    ucmd.arg("--exchange").arg("a").arg("b").succeeds();
+
+    assert_eq!(at.read("a"), "content of b");
+    assert_eq!(at.read("b"), "content of a");
+}
+
+#[test]
+fn test_mv_exchange_requires_two_paths() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir("dir");
+    at.touch("a");
+    at.touch("b");
+
+    ucmd.arg("--exchange")
+        .arg("a")
+        .arg("b")
+        .arg("dir")
+        .fails()
+        .stderr_contains("--exchange requires exactly two paths");
+}
+
 // Todo:
 
 // $ at.touch a b
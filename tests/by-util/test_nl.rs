@@ -635,6 +635,17 @@ fn test_empty_section_delimiter() {
     }
 }
 
+#[test]
+fn test_section_delimiter_repeated_more_than_three_times_is_not_a_delimiter() {
+    // GNU nl only recognizes the delimiter pattern repeated exactly one,
+    // two, or three times as a footer, body, or header marker
+    // respectively; any other repeat count is just a regular line.
+    new_ucmd!()
+        .pipe_in("a\n\\:\\:\\:\\:\nb")
+        .succeeds()
+        .stdout_is("     1\ta\n     2\t\\:\\:\\:\\:\n     3\tb\n");
+}
+
 #[test]
 fn test_directory_as_input() {
     let (at, mut ucmd) = at_and_ucmd!();
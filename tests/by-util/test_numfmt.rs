@@ -419,6 +419,14 @@ fn test_format_all_fields() {
     }
 }
 
+#[test]
+fn test_format_open_ended_field_range() {
+    new_ucmd!()
+        .args(&["--from=auto", "--field", "4-", "1K 2K 3K 4K 5K 6K"])
+        .succeeds()
+        .stdout_only("1K 2K 3K 4000 5000 6000\n");
+}
+
 #[test]
 fn test_should_succeed_if_range_out_of_bounds() {
     new_ucmd!()
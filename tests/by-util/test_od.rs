@@ -313,6 +313,38 @@ fn test_f64() {
         .stdout_is(expected_output);
 }
 
+#[test]
+fn test_f128() {
+    // binary128 values, little-endian: 1.0, -2.0, +inf, NaN
+    let input: [u8; 64] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0x3f, // 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xc0, // -2.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0x7f, // +inf
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0x7f, // NaN
+    ];
+    let expected_output = unindent(
+        "
+            0000000                       1.0000000000000000
+            0000020                      -2.0000000000000000
+            0000040                                      inf
+            0000060                                      NaN
+            0000100
+            ",
+    );
+    new_ucmd!()
+        .arg("--endian=little")
+        .arg("-t")
+        .arg("fL")
+        .run_piped_stdin(&input[..])
+        .success()
+        .no_stderr()
+        .stdout_is(expected_output);
+}
+
 #[test]
 fn test_multibyte() {
     new_ucmd!()
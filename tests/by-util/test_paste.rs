@@ -193,6 +193,22 @@ fn test_delimiter_list_ending_with_unescaped_backslash() {
     }
 }
 
+#[test]
+fn test_multi_byte_delimiter_list() {
+    // Delimiters are cycled per-char, not per-byte, so a list containing a
+    // multi-byte UTF-8 character must still be treated as a single element
+    // of the cycle, the same as an ASCII character.
+    for d in ["-d", "--delimiters"] {
+        let (at, mut ucmd) = at_and_ucmd!();
+        at.write("in0", "a\nb\nc\n");
+        at.write("in1", "1\n2\n3\n");
+
+        ucmd.args(&[d, "é,", "in0", "in1", "in0"])
+            .succeeds()
+            .stdout_is("aé1,a\nbé2,b\ncé3,c\n");
+    }
+}
+
 #[test]
 fn test_data() {
     for example in EXAMPLE_DATA {
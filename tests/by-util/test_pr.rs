@@ -451,6 +451,24 @@ fn test_with_join_lines_option() {
         );
 }
 
+#[test]
+fn test_with_page_width_option() {
+    new_ucmd!()
+        .args(&["-t", "-l", "3", "-W", "10"])
+        .pipe_in("alpha\nbeta\ngamma\n")
+        .succeeds()
+        .stdout_is("alpha     \nbeta      \ngamma     \n\n");
+}
+
+#[test]
+fn test_with_column_width_option() {
+    new_ucmd!()
+        .args(&["-t", "-l", "3", "-w", "10", "-2"])
+        .pipe_in("alpha\nbeta\ngamma\n")
+        .succeeds()
+        .stdout_is("alph\t\nbeta\t\ngamm\t\n\n");
+}
+
 #[test]
 fn test_value_for_number_lines() {
     // *5 is of the form [SEP[NUMBER]] so is accepted and succeeds
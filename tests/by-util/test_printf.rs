@@ -134,6 +134,16 @@ fn sub_b_string_ignore_subs() {
         .stdout_only("hello world %% %i");
 }
 
+#[test]
+fn sub_b_string_stops_output_at_backslash_c() {
+    // `\c` in a %b argument stops all further printf output, not just the
+    // rest of that one argument.
+    new_ucmd!()
+        .args(&["hello %b world\n", "foo\\cbar"])
+        .succeeds()
+        .stdout_only("hello foo");
+}
+
 #[test]
 fn sub_q_string_non_printable() {
     new_ucmd!()
@@ -365,7 +375,6 @@ fn sub_num_dec_trunc() {
         .stdout_only("pi is ~ 3.14159");
 }
 
-#[cfg_attr(not(feature = "test_unimplemented"), ignore)]
 #[test]
 fn sub_num_hex_float_lower() {
     new_ucmd!()
@@ -374,7 +383,6 @@ fn sub_num_hex_float_lower() {
         .stdout_only("0xep-4");
 }
 
-#[cfg_attr(not(feature = "test_unimplemented"), ignore)]
 #[test]
 fn sub_num_hex_float_upper() {
     new_ucmd!()
@@ -383,6 +391,25 @@ fn sub_num_hex_float_upper() {
         .stdout_only("0XEP-4");
 }
 
+// Reference outputs below are `printf(1)`'s (glibc 2.36) for values that
+// round-trip exactly through `f64`, so there is no long-double-vs-double
+// precision gap to account for.
+#[test]
+fn sub_num_hex_float_glibc_reference_values() {
+    new_ucmd!()
+        .args(&["%a %a %a %a", "1", "255.5", "100", "-2"])
+        .succeeds()
+        .stdout_only("0x8p-3 0xf.f8p+4 0xc.8p+3 -0x8p-2");
+}
+
+#[test]
+fn sub_num_hex_float_explicit_precision() {
+    new_ucmd!()
+        .args(&["%.3a", "1.0"])
+        .succeeds()
+        .stdout_only("0x8.000p-3");
+}
+
 #[test]
 fn sub_min_width() {
     new_ucmd!()
@@ -777,3 +804,46 @@ fn format_spec_zero_string_fails() {
     // It is invalid to have the format spec '%0s'
     new_ucmd!().args(&["%0s", "3"]).fails().code_is(1);
 }
+
+#[test]
+fn apostrophe_flag_groups_decimal_digits() {
+    new_ucmd!()
+        .args(&["%'d\n", "1234567"])
+        .succeeds()
+        .stdout_only("1,234,567\n");
+    new_ucmd!()
+        .args(&["%'d\n", "-1234567"])
+        .succeeds()
+        .stdout_only("-1,234,567\n");
+    new_ucmd!()
+        .args(&["%'d\n", "123"])
+        .succeeds()
+        .stdout_only("123\n");
+    new_ucmd!()
+        .args(&["%'u\n", "1234567"])
+        .succeeds()
+        .stdout_only("1,234,567\n");
+    new_ucmd!()
+        .args(&["%'.2f\n", "1234567.891"])
+        .succeeds()
+        .stdout_only("1,234,567.89\n");
+}
+
+#[test]
+fn apostrophe_flag_ignored_for_non_decimal_specs() {
+    // uutils has no locale support, so the `'` flag always groups in
+    // threes with a comma. glibc silently ignores `'` for specifiers
+    // where digit grouping has no defined meaning; we match that.
+    new_ucmd!()
+        .args(&["%'x\n", "4096"])
+        .succeeds()
+        .stdout_only("1000\n");
+    new_ucmd!()
+        .args(&["%'o\n", "8"])
+        .succeeds()
+        .stdout_only("10\n");
+    new_ucmd!()
+        .args(&["%'e\n", "1234567"])
+        .succeeds()
+        .stdout_only("1.234567e+06\n");
+}
@@ -351,6 +351,15 @@ fn test_canonicalize_trailing_slash_symlink_loop() {
     }
 }
 
+#[test]
+fn test_canonicalize_missing_symlink_loop() {
+    // -m must detect ELOOP just like -f and -e, even though it otherwise
+    // tolerates missing path components.
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.relative_symlink_file("loop", "loop");
+    ucmd.args(&["-m", "loop"]).fails().code_is(1).no_stdout();
+}
+
 #[test]
 #[cfg(not(windows))]
 fn test_delimiters() {
@@ -310,6 +310,17 @@ fn test_relative_base_not_prefix_of_relative_to() {
     result.stdout_is("/usr\n/usr/local\n");
 }
 
+#[test]
+fn test_relative_to_with_zero_terminator() {
+    let result = new_ucmd!()
+        .args(&["-m", "-z", "--relative-to=prefix", "prefixed/1"])
+        .succeeds();
+    #[cfg(not(windows))]
+    result.stdout_only("../prefixed/1\u{0}");
+    #[cfg(windows)]
+    result.stdout_only("..\\prefixed\\1\u{0}");
+}
+
 #[test]
 fn test_relative_string_handling() {
     let result = new_ucmd!()
@@ -711,3 +711,54 @@ fn test_non_utf8() {
     ucmd.arg(file).succeeds();
     assert!(!at.file_exists(file));
 }
+
+#[test]
+fn test_rm_one_file_system() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let dir = "test_rm_one_file_system_dir";
+
+    at.mkdir(dir);
+    at.touch(format!("{dir}/file"));
+
+    ucmd.arg("-r")
+        .arg("--one-file-system")
+        .arg(dir)
+        .succeeds()
+        .no_stderr();
+
+    assert!(!at.dir_exists(dir));
+}
+
+#[test]
+fn test_rm_recursive_wide_tree() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let dir = "test_rm_recursive_wide_tree_dir";
+
+    at.mkdir(dir);
+    for i in 0..64 {
+        let sub = format!("{dir}/sub{i}");
+        at.mkdir(&sub);
+        at.touch(format!("{sub}/file"));
+    }
+
+    ucmd.arg("-r").arg(dir).succeeds().no_stderr();
+
+    assert!(!at.dir_exists(dir));
+}
+
+#[test]
+fn test_rm_preserve_root_all_same_file_system() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let dir = "test_rm_preserve_root_all_dir";
+
+    at.mkdir(dir);
+    at.touch(format!("{dir}/file"));
+
+    ucmd.arg("-r")
+        .arg("--preserve-root=all")
+        .arg(dir)
+        .succeeds()
+        .no_stderr();
+
+    assert!(!at.dir_exists(dir));
+}
@@ -81,6 +81,53 @@ fn test_hex_big_number() {
         );
 }
 
+/// C99 hex floats (`0x` mantissa, optional fraction, optional `p`
+/// binary exponent) are accepted for FIRST/INCREMENT/LAST, matching
+/// GNU seq exactly, e.g. `0x1.8p3` is `1.5₁₆ × 2³ = 12`.
+#[test]
+fn test_hex_float() {
+    new_ucmd!()
+        .args(&["0x1.8p3", "0x1.8p3"])
+        .succeeds()
+        .stdout_only("12\n");
+    new_ucmd!()
+        .args(&["0x1.8", "0x1.8"])
+        .succeeds()
+        .stdout_only("1.5\n");
+    new_ucmd!()
+        .args(&["0x1p-2", "0x1p-2"])
+        .succeeds()
+        .stdout_only("0.25\n");
+    new_ucmd!()
+        .args(&["--", "-0x1.8p3", "1", "0x2.8p3"])
+        .succeeds()
+        .stdout_only("-12\n-11\n-10\n-9\n-8\n-7\n-6\n-5\n-4\n-3\n-2\n-1\n0\n1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\n16\n17\n18\n19\n20\n");
+}
+
+/// Even when an operand is given in scientific notation, GNU seq's default
+/// (non `-f`, non `-w`) output is always expanded to plain decimal, never
+/// scientific notation — confirmed against GNU seq itself, which prints
+/// `100000000000000000000`, not `1e+20`, for `seq 1e20 1e20`.
+#[test]
+fn test_default_output_never_uses_scientific_notation() {
+    new_ucmd!()
+        .args(&["1e6", "1e6", "3e6"])
+        .succeeds()
+        .stdout_only("1000000\n2000000\n3000000\n");
+    new_ucmd!()
+        .args(&["1e20", "1e20"])
+        .succeeds()
+        .stdout_only("100000000000000000000\n");
+    new_ucmd!()
+        .args(&["1e-20", "1e-20"])
+        .succeeds()
+        .stdout_only("0.00000000000000000001\n");
+    new_ucmd!()
+        .args(&["6.02e23", "6.02e23"])
+        .succeeds()
+        .stdout_only("602000000000000000000000\n");
+}
+
 #[test]
 fn test_hex_identifier_in_wrong_place() {
     new_ucmd!()
@@ -106,6 +153,35 @@ fn test_rejects_non_floats() {
         .usage_error("invalid floating point argument: 'foo'");
 }
 
+/// glibc's `strtod` also accepts `nan(n-char-sequence)`, e.g. `nan(123)`,
+/// as a NaN literal, so it gets the same "not-a-number" diagnostic as a
+/// bare `nan` rather than the generic parse-failure message.
+#[test]
+fn test_rejects_nan_with_n_char_sequence() {
+    new_ucmd!()
+        .arg("nan(123)")
+        .fails()
+        .usage_error("invalid 'not-a-number' argument: 'nan(123)'");
+    new_ucmd!()
+        .arg("nan()")
+        .fails()
+        .usage_error("invalid 'not-a-number' argument: 'nan()'");
+    new_ucmd!()
+        .args(&["1", "nan(xyz)"])
+        .fails()
+        .usage_error("invalid 'not-a-number' argument: 'nan(xyz)'");
+}
+
+/// A string that merely starts with "nan" but is not a valid NaN literal
+/// (no parenthesized suffix) is a plain parse failure, not a NaN error.
+#[test]
+fn test_nan_prefix_without_parens_is_not_nan() {
+    new_ucmd!()
+        .arg("nanabc")
+        .fails()
+        .usage_error("invalid floating point argument: 'nanabc'");
+}
+
 #[test]
 fn test_accepts_option_argument_directly() {
     new_ucmd!()
@@ -212,10 +288,35 @@ fn test_separator_and_terminator() {
         .args(&["-s", "\n", "2", "6"])
         .run()
         .stdout_is("2\n3\n4\n5\n6\n");
+    // A literal `\n` (backslash followed by `n`) in the separator is now
+    // interpreted as an escape sequence, the same as it would be in a
+    // `printf` format string, rather than passed through verbatim.
     new_ucmd!()
         .args(&["-s", "\\n", "2", "6"])
         .run()
-        .stdout_is("2\\n3\\n4\\n5\\n6\n");
+        .stdout_is("2\n3\n4\n5\n6\n");
+}
+
+/// `-s`/`-t` process backslash escapes like `printf`, including `\0`, so
+/// that the output can feed `xargs -0`.
+#[test]
+fn test_separator_and_terminator_escape_sequences() {
+    new_ucmd!()
+        .args(&["-s", "\\t", "1", "3"])
+        .succeeds()
+        .stdout_only("1\t2\t3\n");
+    new_ucmd!()
+        .args(&["-t", "\\t", "1", "2"])
+        .succeeds()
+        .stdout_only("1\n2\t");
+    new_ucmd!()
+        .args(&["-s", "\\0", "1", "3"])
+        .succeeds()
+        .stdout_only("1\x002\x003\n");
+    new_ucmd!()
+        .args(&["-s", "\\\\", "1", "3"])
+        .succeeds()
+        .stdout_only("1\\2\\3\n");
 }
 
 #[test]
@@ -229,11 +330,40 @@ fn test_equalize_widths() {
     }
 }
 
+#[test]
+fn test_equalize_widths_negative_range() {
+    new_ucmd!()
+        .args(&["-w", "-5", "5"])
+        .run()
+        .stdout_is("-5\n-4\n-3\n-2\n-1\n00\n01\n02\n03\n04\n05\n");
+}
+
 #[test]
 fn test_seq_wrong_arg() {
     new_ucmd!().args(&["-w", "5", "10", "33", "32"]).fails();
 }
 
+/// An empty sequence (FIRST after LAST with the default positive
+/// increment) must produce zero bytes, not even the terminator.
+#[test]
+fn test_empty_range_produces_no_output() {
+    new_ucmd!()
+        .args(&["5", "1"])
+        .succeeds()
+        .stdout_is("")
+        .stdout_is_bytes(b"");
+}
+
+/// A single-element sequence writes only the value plus the terminator,
+/// with no leading or trailing separator.
+#[test]
+fn test_single_element_range_has_no_separator() {
+    new_ucmd!()
+        .args(&["3", "3"])
+        .succeeds()
+        .stdout_is_bytes(b"3\n");
+}
+
 #[test]
 fn test_zero_step() {
     new_ucmd!().args(&["10", "0", "32"]).fails();
@@ -250,6 +380,66 @@ fn test_big_numbers() {
         .stdout_only("1000000000000000000000000000\n1000000000000000000000000001\n");
 }
 
+#[test]
+fn test_exact_integers_beyond_u64_max() {
+    new_ucmd!()
+        .args(&["18446744073709551615", "18446744073709551617"])
+        .succeeds()
+        .stdout_only("18446744073709551615\n18446744073709551616\n18446744073709551617\n");
+}
+
+#[test]
+fn test_exact_integers_beyond_i128_max() {
+    new_ucmd!()
+        .args(&[
+            "170141183460469231731687303715884105727",
+            "170141183460469231731687303715884105729",
+        ])
+        .succeeds()
+        .stdout_only(
+            "170141183460469231731687303715884105727\n\
+             170141183460469231731687303715884105728\n\
+             170141183460469231731687303715884105729\n",
+        );
+}
+
+/// An unpadded, unformatted integer range takes the allocation-free
+/// `i128` fast path (falling back to `BigInt`'s `Display` only outside
+/// that range), so a range that steps from a huge negative value up
+/// through zero to a huge positive one must render exactly the same as
+/// the general path, on both sides of the `i128` boundary at once.
+#[test]
+fn test_large_integer_range_crossing_zero() {
+    new_ucmd!()
+        .args(&[
+            "--",
+            "-170141183460469231731687303715884105729",
+            "85070591730234615865843651857942052865",
+            "170141183460469231731687303715884105729",
+        ])
+        .succeeds()
+        .stdout_only(
+            "-170141183460469231731687303715884105729\n\
+             -85070591730234615865843651857942052864\n\
+             1\n\
+             85070591730234615865843651857942052866\n",
+        );
+}
+
+/// The range is computed with exact decimal arithmetic
+/// (`ExtendedBigDecimal`, wrapping `bigdecimal::BigDecimal`), not `f64`
+/// or `f128`, so summing a fractional increment many times over never
+/// drifts off the exact grid the way naively accumulating `0.1` in
+/// binary floating point would.
+#[test]
+fn test_fractional_increment_has_no_accumulated_rounding_error() {
+    new_ucmd!().args(&["0", "0.1", "3"]).succeeds().stdout_is(
+        (0..=30)
+            .map(|i| format!("{:.1}\n", f64::from(i) / 10.0))
+            .collect::<String>(),
+    );
+}
+
 // ---- Tests for the floating point based path ----
 
 #[test]
@@ -588,6 +778,25 @@ fn test_width_negative_scientific_notation() {
         .no_stderr();
 }
 
+/// A negative FIRST given in scientific notation with no decimal point
+/// (e.g. `-1e2`) used to count its own minus sign twice when computing
+/// the integral width, padding every line with one extra digit.
+#[test]
+fn test_width_negative_scientific_notation_no_decimal() {
+    new_ucmd!()
+        .args(&["-w", "--", "-1e2", "50", "1e2"])
+        .succeeds()
+        .stdout_is(
+            "-100
+-050
+0000
+0050
+0100
+",
+        )
+        .no_stderr();
+}
+
 /// Test that trailing zeros in the end argument do not contribute to width.
 #[test]
 fn test_width_decimal_scientific_notation_trailing_zeros_end() {
@@ -633,6 +842,23 @@ fn test_inf() {
     run(&["inf"], b"1\n2\n3\n");
 }
 
+/// An infinite LAST is not limited to the two-operand form: it also
+/// short-circuits the termination check in the three-operand
+/// FIRST INCREMENT LAST form, with a fractional increment.
+#[test]
+fn test_inf_last_with_explicit_increment() {
+    run(&["1", "0.5", "inf"], b"1.0\n1.5\n2.0\n");
+}
+
+/// Every write inside `print_seq`, including on the `-f`/`--format` code
+/// path, propagates `io::Error` up to the single `BrokenPipe` check in
+/// `uumain`, so closing the reader early exits cleanly instead of
+/// panicking or reporting a write error.
+#[test]
+fn test_broken_pipe_on_format_path() {
+    run(&["-f", "%.0f", "inf"], b"1\n2\n3\n");
+}
+
 #[test]
 fn test_infinity() {
     run(&["infinity"], b"1\n2\n3\n");
@@ -706,6 +932,22 @@ fn test_float_precision_increment() {
         .no_stderr();
 }
 
+#[test]
+fn test_precision_from_increment_with_integer_bounds() {
+    new_ucmd!()
+        .args(&["1", "0.25", "2"])
+        .succeeds()
+        .stdout_only("1.00\n1.25\n1.50\n1.75\n2.00\n");
+}
+
+#[test]
+fn test_precision_from_increment_with_integer_first() {
+    new_ucmd!()
+        .args(&["0", "0.125", "0.5"])
+        .succeeds()
+        .stdout_only("0.000\n0.125\n0.250\n0.375\n0.500\n");
+}
+
 /// Test for floating point precision issues.
 #[test]
 fn test_negative_increment_decimal() {
@@ -758,6 +1000,146 @@ fn test_format_option() {
         .stdout_only("0.00\n0.10\n0.20\n0.30\n0.40\n0.50\n");
 }
 
+#[test]
+fn test_format_plus_flag() {
+    new_ucmd!()
+        .args(&["-f", "%+.1f", "-2", "1", "2"])
+        .succeeds()
+        .stdout_only("-2.0\n-1.0\n+0.0\n+1.0\n+2.0\n");
+}
+
+#[test]
+fn test_format_space_flag() {
+    new_ucmd!()
+        .args(&["-f", "% .1f", "-2", "1", "2"])
+        .succeeds()
+        .stdout_only("-2.0\n-1.0\n 0.0\n 1.0\n 2.0\n");
+}
+
+#[test]
+fn test_format_option_honors_separator_and_terminator() {
+    new_ucmd!()
+        .args(&["-f", "%.0f", "-s", ",", "1", "3"])
+        .succeeds()
+        .stdout_only("1,2,3\n");
+}
+
+/// `%.0f` rounds each value to the nearest integer, round-half-to-even,
+/// rather than truncating.
+#[test]
+fn test_format_zero_precision_rounds_to_integer() {
+    new_ucmd!()
+        .args(&["-f", "%.0f", "1.4", "1", "4.4"])
+        .succeeds()
+        .stdout_only("1\n2\n3\n4\n");
+}
+
+/// Values ending in exactly `.5` round to the nearest even integer, the
+/// same way C's `printf` (and IEEE 754 binary-to-decimal conversion)
+/// does, rather than always rounding away from zero.
+#[test]
+fn test_format_zero_precision_rounds_half_to_even() {
+    new_ucmd!()
+        .args(&["-f", "%.0f", "0.5", "1", "4.5"])
+        .succeeds()
+        .stdout_only("0\n2\n2\n4\n4\n");
+}
+
+/// Rounding to fewer decimals than the value carries also follows
+/// round-half-to-even, matching GNU seq exactly on half-way cases.
+#[test]
+fn test_format_rounds_half_to_even_at_nonzero_precision() {
+    new_ucmd!()
+        .args(&["-f", "%.1f", "0.25", "0.25", "0.75"])
+        .succeeds()
+        .stdout_only("0.2\n0.5\n0.8\n");
+}
+
+/// `%f` must format the exact decimal value, not the nearest `f64`. An
+/// integer with more significant digits than `f64` can represent exactly
+/// would otherwise come out rounded to a multiple of a power of two.
+#[test]
+fn test_format_decimal_preserves_precision_beyond_f64() {
+    new_ucmd!()
+        .args(&[
+            "-f",
+            "%.0f",
+            "100000000000000000001",
+            "100000000000000000001",
+        ])
+        .succeeds()
+        .stdout_only("100000000000000000001\n");
+}
+
+/// `%a` matches GNU's hexadecimal floating point output, which keeps a
+/// full nibble for the leading digit (using the implicit mantissa bit
+/// as its high bit) rather than normalizing it down to `1`.
+#[test]
+fn test_format_hexadecimal_float() {
+    new_ucmd!()
+        .args(&["-f", "%a", "1", "1"])
+        .succeeds()
+        .stdout_only("0x8p-3\n");
+    new_ucmd!()
+        .args(&["-f", "%a", "--", "-2", "-2"])
+        .succeeds()
+        .stdout_only("-0x8p-2\n");
+    new_ucmd!()
+        .args(&["-f", "%.1a", ".875", ".875"])
+        .succeeds()
+        .stdout_only("0xe.0p-4\n");
+}
+
+/// `%x` has no floating-point meaning, so it is rejected just like GNU
+/// rejects it, rather than being silently treated as an integer format.
+#[test]
+fn test_format_rejects_integer_directive() {
+    new_ucmd!().args(&["-f", "%x", "1", "2"]).fails();
+}
+
+/// The `'` flag groups the integral digits of `%f`/`%g` output in threes,
+/// the same way it would with `printf`. There is no locale support in this
+/// codebase, so the separator is always a comma.
+#[test]
+fn test_format_apostrophe_flag_groups_digits() {
+    new_ucmd!()
+        .args(&["-f", "%'.0f", "1234567", "1234567"])
+        .succeeds()
+        .stdout_only("1,234,567\n");
+    new_ucmd!()
+        .args(&["-f", "%'.0f", "999", "999"])
+        .succeeds()
+        .stdout_only("999\n");
+}
+
+/// A range that steps through zero prints the plain, positive `0`, since
+/// no operand was ever the literal `-0`.
+#[test]
+fn test_negative_zero_crossover_prints_plain_zero() {
+    new_ucmd!()
+        .args(&["--", "-1", "1"])
+        .succeeds()
+        .stdout_only("-1\n0\n1\n");
+    new_ucmd!()
+        .args(&["-f", "%g", "--", "-1", "1"])
+        .succeeds()
+        .stdout_only("-1\n0\n1\n");
+}
+
+/// An explicit `-0` operand keeps its sign in the output, both in the
+/// default rendering and under `-f '%g'`.
+#[test]
+fn test_explicit_negative_zero_keeps_its_sign() {
+    new_ucmd!()
+        .args(&["--", "-0", "0"])
+        .succeeds()
+        .stdout_only("-0\n");
+    new_ucmd!()
+        .args(&["-f", "%g", "--", "-0", "0"])
+        .succeeds()
+        .stdout_only("-0\n");
+}
+
 #[test]
 fn test_invalid_zero_increment_value() {
     new_ucmd!()
@@ -800,3 +1182,308 @@ fn test_invalid_format() {
         .no_stdout()
         .stderr_contains("format '%g%g' has too many % directives");
 }
+
+#[test]
+fn test_descending_negative_increment() {
+    new_ucmd!()
+        .args(&["5", "-1", "1"])
+        .succeeds()
+        .stdout_only("5\n4\n3\n2\n1\n");
+}
+
+#[test]
+fn test_descending_negative_increment_floats() {
+    new_ucmd!()
+        .args(&["2.5", "-0.5", "1"])
+        .succeeds()
+        .stdout_only("2.5\n2.0\n1.5\n1.0\n");
+}
+
+#[test]
+fn test_version() {
+    for version_flg in ["-V", "--version"] {
+        assert!(new_ucmd!()
+            .arg(version_flg)
+            .succeeds()
+            .no_stderr()
+            .stdout_str()
+            .starts_with("seq"));
+    }
+}
+
+#[test]
+fn test_help_mentions_new_flags() {
+    new_ucmd!()
+        .arg("--help")
+        .succeeds()
+        .no_stderr()
+        .stdout_contains("--grid-origin")
+        .stdout_contains("--emit-empty-as");
+}
+
+#[test]
+fn test_grid_origin() {
+    new_ucmd!()
+        .args(&["--grid-origin", "0.5", "--grid-step", "1", "0", "0.3", "3"])
+        .succeeds()
+        .stdout_only("0.5\n1.5\n2.5\n3.5\n");
+}
+
+#[test]
+fn test_grid_origin_requires_grid_step() {
+    new_ucmd!()
+        .args(&["--grid-origin", "0.5", "1", "3"])
+        .fails();
+}
+
+#[test]
+fn test_inf_mixed_case() {
+    run(&["INFINITY"], b"1\n2\n3\n");
+    run(&["--", "-INFINITY", "0"], b"-inf\n-inf\n-inf\n");
+}
+
+#[test]
+fn test_empty_operand_is_clear_parse_error() {
+    new_ucmd!()
+        .args(&[""])
+        .fails()
+        .no_stdout()
+        .usage_error("invalid floating point argument: ''");
+}
+
+#[test]
+fn test_whitespace_operand_is_clear_parse_error() {
+    new_ucmd!()
+        .args(&["1", "  ", "5"])
+        .fails()
+        .no_stdout()
+        .usage_error("invalid floating point argument: '  '");
+}
+
+#[test]
+fn test_explain_precision() {
+    new_ucmd!()
+        .args(&["--explain-precision", "1", "0.1", "2"])
+        .succeeds()
+        .stderr_contains("--explain-precision: first: integral=1, fractional=0, exact_integer=true")
+        .stderr_contains(
+            "--explain-precision: increment: integral=1, fractional=1, exact_integer=false",
+        )
+        .stderr_contains("--explain-precision: last: integral=1, fractional=0, exact_integer=true")
+        .stderr_contains("--explain-precision: padding=1, largest_dec=1");
+}
+
+#[test]
+fn test_leading_plus_sign() {
+    new_ucmd!()
+        .args(&["+1", "+5"])
+        .succeeds()
+        .stdout_only("1\n2\n3\n4\n5\n");
+}
+
+#[test]
+fn test_percentage_increment() {
+    new_ucmd!()
+        .args(&["0", "25%", "100"])
+        .succeeds()
+        .stdout_only("0\n25\n50\n75\n100\n");
+}
+
+#[test]
+fn test_duration_base() {
+    new_ucmd!()
+        .args(&["--duration-base", "3661", "0", "1", "3"])
+        .succeeds()
+        .stdout_only("01:01:01\n01:01:02\n01:01:03\n01:01:04\n");
+}
+
+#[test]
+fn test_duration_base_negative_and_overflow() {
+    new_ucmd!()
+        .args(&["--duration-base", "-2", "0", "1", "2"])
+        .succeeds()
+        .stdout_only("-00:00:02\n-00:00:01\n00:00:00\n");
+}
+
+#[test]
+fn test_emit_empty_as() {
+    new_ucmd!()
+        .args(&["--emit-empty-as", "none", "2", "1"])
+        .succeeds()
+        .stdout_only("none\n");
+}
+
+#[test]
+fn test_emit_empty_as_not_used_when_nonempty() {
+    new_ucmd!()
+        .args(&["--emit-empty-as", "none", "1", "3"])
+        .succeeds()
+        .stdout_only("1\n2\n3\n");
+}
+
+#[test]
+fn test_down_counts_from_last_to_one() {
+    new_ucmd!()
+        .args(&["--down", "5"])
+        .succeeds()
+        .stdout_only("5\n4\n3\n2\n1\n");
+}
+
+#[test]
+fn test_down_rejects_multiple_operands() {
+    new_ucmd!().args(&["--down", "1", "5"]).fails();
+}
+
+#[test]
+fn test_single_arg_ascending_default() {
+    new_ucmd!()
+        .args(&["5"])
+        .succeeds()
+        .stdout_only("1\n2\n3\n4\n5\n");
+}
+
+#[test]
+fn test_single_arg_negative_last_is_empty() {
+    new_ucmd!().args(&["-5"]).succeeds().no_stdout();
+}
+
+#[test]
+fn test_extreme_precision_completes_with_correct_leading_zeros() {
+    let mut zeros = "0".repeat(3999);
+    zeros.push('1');
+    let expected_first = format!("0.{zeros}\n");
+    zeros.pop();
+    zeros.push('2');
+    let expected_last = format!("0.{zeros}\n");
+    new_ucmd!()
+        .args(&["1e-4000", "1e-4000", "2e-4000"])
+        .succeeds()
+        .stdout_only(format!("{expected_first}{expected_last}"));
+}
+
+#[test]
+fn test_range_crossing_u64_max_does_not_overflow() {
+    // The term values here cross `u64::MAX` (18446744073709551615), well
+    // past what a `u64`/`i64` loop counter could represent, but there are
+    // only 3 terms to produce: `seq` advances the range with arbitrary
+    // precision arithmetic on the terms themselves rather than counting
+    // iterations in a fixed-width integer.
+    new_ucmd!()
+        .args(&[
+            "18446744073709551616",
+            "18446744073709551616",
+            "55340232221128654848",
+        ])
+        .succeeds()
+        .stdout_only("18446744073709551616\n36893488147419103232\n55340232221128654848\n");
+}
+
+#[test]
+fn test_output_writes_to_file_instead_of_stdout() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    let output_path = at.plus_as_string("out.txt");
+
+    ts.ucmd()
+        .args(&["--output", &output_path, "1", "3"])
+        .succeeds()
+        .no_stdout();
+
+    assert_eq!(at.read("out.txt"), "1\n2\n3\n");
+}
+
+#[test]
+fn test_output_short_flag() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    let output_path = at.plus_as_string("out.txt");
+
+    ts.ucmd()
+        .args(&["-o", &output_path, "3"])
+        .succeeds()
+        .no_stdout();
+
+    assert_eq!(at.read("out.txt"), "1\n2\n3\n");
+}
+
+#[test]
+fn test_wait_for_reader_requires_output() {
+    new_ucmd!().args(&["--wait-for-reader", "3"]).fails();
+}
+
+#[test]
+fn test_double_dash_allows_negative_operands() {
+    new_ucmd!()
+        .args(&["--", "-5", "5"])
+        .succeeds()
+        .stdout_only("-5\n-4\n-3\n-2\n-1\n0\n1\n2\n3\n4\n5\n");
+}
+
+#[test]
+fn test_double_dash_allows_negative_increment_and_last() {
+    new_ucmd!()
+        .args(&["--", "-5", "-1", "-10"])
+        .succeeds()
+        .stdout_only("-5\n-6\n-7\n-8\n-9\n-10\n");
+}
+
+#[test]
+fn test_double_dash_with_too_many_operands_is_arity_error() {
+    new_ucmd!().args(&["--", "1", "2", "3", "4"]).fails();
+}
+
+#[test]
+fn test_max_field_width_leaves_short_values_untouched() {
+    new_ucmd!()
+        .args(&["--max-field-width", "3", "100", "105"])
+        .succeeds()
+        .stdout_only("100\n101\n102\n103\n104\n105\n");
+}
+
+#[test]
+fn test_max_field_width_truncates_long_values() {
+    new_ucmd!()
+        .args(&["--max-field-width", "3", "-100", "-95"])
+        .succeeds()
+        .stdout_only("-1…\n-99\n-98\n-97\n-96\n-95\n");
+}
+
+#[test]
+fn test_max_field_width_errors_if_sign_would_be_lost() {
+    new_ucmd!()
+        .args(&["--max-field-width", "1", "-100", "-95"])
+        .fails();
+}
+
+#[test]
+fn test_empty_separator_concatenates_numbers() {
+    new_ucmd!()
+        .args(&["-s", "", "1", "3"])
+        .succeeds()
+        .stdout_only("123\n");
+}
+
+#[test]
+fn test_empty_separator_single_value() {
+    new_ucmd!()
+        .args(&["-s", "", "1", "1"])
+        .succeeds()
+        .stdout_only("1\n");
+}
+
+#[test]
+fn test_with_constant_numeric() {
+    new_ucmd!()
+        .args(&["--with-constant", "0", "1", "3"])
+        .succeeds()
+        .stdout_only("1 0\n2 0\n3 0\n");
+}
+
+#[test]
+fn test_with_constant_string() {
+    new_ucmd!()
+        .args(&["--with-constant", "hello", "1", "3"])
+        .succeeds()
+        .stdout_only("1 hello\n2 hello\n3 hello\n");
+}
+
@@ -176,6 +176,59 @@ fn test_shred_empty() {
     assert!(!at.file_exists(file_a));
 }
 
+#[test]
+fn test_shred_random_source() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    let file = "test_shred_random_source";
+    let source = "random_source_bytes";
+    at.write(file, "0123456789");
+    at.write(source, "abcdefghij");
+
+    ucmd.arg("-n")
+        .arg("1")
+        .arg("-x")
+        .arg("--random-source")
+        .arg(source)
+        .arg(file)
+        .succeeds();
+
+    // With a single random pass and a file the same length as the source,
+    // the file's contents become exactly the random source's bytes.
+    assert_eq!(at.read_bytes(file), at.read_bytes(source));
+}
+
+#[test]
+fn test_shred_random_source_too_short() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    let file = "test_shred_random_source_too_short";
+    let source = "random_source_bytes_too_short";
+    at.write(file, "0123456789");
+    at.write(source, "abc");
+
+    // The random source has fewer bytes than needed for a single pass;
+    // this must fail cleanly instead of panicking.
+    ucmd.arg("-n")
+        .arg("1")
+        .arg("-x")
+        .arg("--random-source")
+        .arg(source)
+        .arg(file)
+        .fails()
+        .stderr_contains("File write pass failed");
+}
+
+#[test]
+fn test_shred_random_source_missing() {
+    new_ucmd!()
+        .arg("--random-source")
+        .arg("this-file-does-not-exist")
+        .arg("file")
+        .fails()
+        .stderr_contains("failed to open random source");
+}
+
 #[test]
 #[cfg(all(unix, feature = "chmod"))]
 fn test_shred_fail_no_perm() {
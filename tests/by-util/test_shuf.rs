@@ -387,6 +387,64 @@ fn test_head_count() {
     );
 }
 
+#[test]
+fn test_head_count_reservoir_sampling_large_input() {
+    // `-n K` on a stdin stream much bigger than K goes through the
+    // streaming reservoir sampler rather than buffering everything, but
+    // the observable result must still be a genuine, duplicate-free
+    // sample of size K drawn from the input.
+    let head_count = 7;
+    let input_seq: Vec<i32> = (1..=10_000).collect();
+    let input = input_seq
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let result = new_ucmd!()
+        .args(&["-n", &head_count.to_string()])
+        .pipe_in(input.as_bytes())
+        .succeeds();
+    result.no_stderr();
+
+    let mut result_seq: Vec<i32> = result
+        .stdout_str()
+        .split('\n')
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().unwrap())
+        .collect();
+    assert_eq!(result_seq.len(), head_count, "Output is not limited");
+    let mut deduped = result_seq.clone();
+    deduped.sort_unstable();
+    deduped.dedup();
+    assert_eq!(deduped.len(), result_seq.len(), "Output contains duplicates");
+    result_seq.sort_unstable();
+    assert!(
+        result_seq.iter().all(|x| input_seq.contains(x)),
+        "Output includes element not from input"
+    );
+}
+
+#[test]
+fn test_head_count_larger_than_input() {
+    // When K exceeds the number of input lines, the streaming reservoir
+    // path should degrade to "shuffle everything", same as without -n.
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("input.txt", "a\nb\nc\n");
+
+    let result = ucmd.args(&["-n", "100", "input.txt"]).succeeds();
+    result.no_stderr();
+
+    let mut result_seq: Vec<String> = result
+        .stdout_str()
+        .split('\n')
+        .filter(|x| !x.is_empty())
+        .map(String::from)
+        .collect();
+    result_seq.sort_unstable();
+    assert_eq!(result_seq, ["a", "b", "c"]);
+}
+
 #[test]
 fn test_zero_head_count_pipe() {
     let result = new_ucmd!().arg("-n0").pipe_in(vec![]).succeeds();
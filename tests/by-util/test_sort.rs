@@ -161,6 +161,24 @@ fn test_human_numeric_whitespace() {
     );
 }
 
+#[test]
+fn test_version_sort_as_key_flag() {
+    new_ucmd!()
+        .args(&["-k1,1V"])
+        .pipe_in("0.1\n0.02\n0.2\n0.002\n0.3\n")
+        .succeeds()
+        .stdout_is("0.1\n0.002\n0.02\n0.2\n0.3\n");
+}
+
+#[test]
+fn test_human_numeric_sort_as_key_flag() {
+    new_ucmd!()
+        .args(&["-k1,1h"])
+        .pipe_in("2K\n1K\n1M\n900\n")
+        .succeeds()
+        .stdout_is("900\n1K\n2K\n1M\n");
+}
+
 // This tests where serde often fails when reading back JSON
 // if it finds a null value
 #[test]
@@ -543,6 +561,16 @@ fn test_keys_custom_separator() {
     test_helper("keys_custom_separator", &["-k 2.2,2.2 -t x"]);
 }
 
+#[test]
+fn test_keys_compound_range_with_modifiers() {
+    // The key spans field 1 char 2 through field 3 char 1, sorted numeric-reverse.
+    new_ucmd!()
+        .args(&["-t:", "-k1.2,3.1nr"])
+        .pipe_in("a10:x:5\na20:y:9\na5:z:1\n")
+        .succeeds()
+        .stdout_is("a20:y:9\na10:x:5\na5:z:1\n");
+}
+
 #[test]
 fn test_keys_invalid_field() {
     new_ucmd!()
@@ -918,6 +946,16 @@ fn test_compress() {
         .stdout_only_fixture("ext_sort.expected");
 }
 
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_compress_with_key() {
+    new_ucmd!()
+        .args(&["-k2,2n", "--compress-program", "gzip", "-S", "10"])
+        .pipe_in("a 3\nb 1\nc 2\n")
+        .succeeds()
+        .stdout_is("b 1\nc 2\na 3\n");
+}
+
 #[test]
 #[cfg(any(target_os = "linux", target_os = "android"))]
 fn test_compress_merge() {
@@ -1001,6 +1039,38 @@ fn test_merge_batch_size() {
         .stdout_only_fixture("merge_ints_interleaved.expected");
 }
 
+#[test]
+fn test_parallel() {
+    for threads in ["1", "2", "4"] {
+        new_ucmd!()
+            .args(&["-n", "--parallel", threads, "ext_sort.txt"])
+            .succeeds()
+            .stdout_only_fixture("ext_sort.expected");
+    }
+}
+
+#[test]
+fn test_parallel_zero_defaults_to_num_cpus() {
+    new_ucmd!()
+        .args(&["-n", "--parallel=0", "ext_sort.txt"])
+        .succeeds()
+        .stdout_only_fixture("ext_sort.expected");
+}
+
+#[test]
+fn test_merge_batches_recursive() {
+    // With a tiny buffer size *and* a tiny batch size, sorting ext_sort.txt
+    // spills many more runs to disk than fit in a single merge batch, so
+    // `merge_with_file_limit` has to recurse through more than one level of
+    // intermediate temporary files before producing the final output.
+    TestScenario::new(util_name!())
+        .ucmd()
+        .timeout(Duration::from_secs(120))
+        .args(&["ext_sort.txt", "-n", "-S", "150b", "--batch-size=2"])
+        .succeeds()
+        .stdout_only_fixture("ext_sort.expected");
+}
+
 #[test]
 fn test_sigpipe_panic() {
     let mut cmd = new_ucmd!();
@@ -356,6 +356,25 @@ fn test_filter_broken_pipe() {
         .succeeds();
 }
 
+#[test]
+#[cfg(unix)]
+fn test_filter_with_elide_empty_files() {
+    // With -e/--elide-empty-files, chunks that would be empty must not be
+    // handed to the --filter command at all.
+    let (at, mut ucmd) = at_and_ucmd!();
+    let name = "small";
+    at.write(name, "a\n");
+
+    ucmd.args(&["-e", "-n", "4", "--filter=cat >> $FILE", name])
+        .succeeds();
+
+    // The 2-byte input only fills 2 of the 4 requested chunks; the other 2
+    // would be empty and must be elided rather than passed to the filter.
+    let glob = Glob::new(&at, ".", r"x[[:alpha:]][[:alpha:]]$");
+    assert_eq!(glob.count(), 2);
+    assert_eq!(glob.collate(), b"a\n");
+}
+
 #[test]
 #[cfg(unix)]
 fn test_filter_with_kth_chunk() {
@@ -944,6 +963,15 @@ fn test_number_n() {
         .stdout_only("");
 }
 
+#[test]
+fn test_number_n_single_chunk() {
+    // `-n 1` is a degenerate case: the whole input is a single chunk.
+    let (at, mut ucmd) = at_and_ucmd!();
+    ucmd.args(&["-n", "1", "asciilowercase.txt"]).succeeds();
+    assert_eq!(at.read("xaa"), at.read("asciilowercase.txt"));
+    assert!(!at.file_exists("xab"));
+}
+
 #[test]
 fn test_number_kth_of_n() {
     new_ucmd!()
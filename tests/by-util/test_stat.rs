@@ -15,6 +15,15 @@ fn test_invalid_option() {
     new_ucmd!().arg("-w").arg("-q").arg("/").fails();
 }
 
+#[test]
+fn test_fs_format_missing_file() {
+    new_ucmd!()
+        .args(&["-f", "/this/path/does/not/exist"])
+        .fails()
+        .code_is(1)
+        .stderr_contains("cannot read file system information for");
+}
+
 #[cfg(unix)]
 const NORMAL_FORMAT_STR: &str =
     "%a %A %b %B %d %D %f %F %g %G %h %i %m %n %o %s %u %U %x %X %y %Y %z %Z"; // avoid "%w %W" (birth/creation) due to `stat` limitations and linux kernel & rust version capability variations
@@ -251,6 +260,17 @@ fn test_printf() {
     ts.ucmd().args(&args).succeeds().stdout_is(expected_stdout);
 }
 
+#[test]
+#[cfg(unix)]
+fn test_format_selinux_context() {
+    // `%C` is always a recognized directive, even on a kernel without
+    // SELinux enabled, in which case it falls back to "?" like GNU does.
+    new_ucmd!()
+        .args(&["-c", "%C", "/"])
+        .succeeds()
+        .stdout_is("?\n");
+}
+
 #[test]
 #[cfg(unix)]
 fn test_pipe_fifo() {
@@ -84,3 +84,27 @@ fn test_stdbuf_invalid_mode_fails() {
         }
     }
 }
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn test_stdbuf_explicit_buffer_size() {
+    // A plain number is a fully-buffered size in bytes, forwarded to
+    // libstdbuf via _STDBUF_O rather than the "0"/"L" special cases.
+    new_ucmd!()
+        .args(&["-o", "1024", "head"])
+        .pipe_in("The quick brown fox jumps over the lazy dog.")
+        .run()
+        .stdout_is("The quick brown fox jumps over the lazy dog.");
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn test_stdbuf_all_three_streams() {
+    // -i, -o and -e all set _STDBUF_I/_STDBUF_O/_STDBUF_E at once and load
+    // the same preload library once for all three streams.
+    new_ucmd!()
+        .args(&["-i", "0", "-o", "L", "-e", "0", "head"])
+        .pipe_in("The quick brown fox jumps over the lazy dog.")
+        .run()
+        .stdout_is("The quick brown fox jumps over the lazy dog.");
+}
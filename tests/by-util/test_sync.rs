@@ -39,6 +39,28 @@ fn test_sync_data() {
     new_ucmd!().arg("--data").arg(&temporary_path).succeeds();
 }
 
+#[test]
+fn test_sync_data_multiple_files() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    at.touch("file1");
+    at.touch("file2");
+    ts.ucmd().arg("--data").arg("file1").arg("file2").succeeds();
+}
+
+#[test]
+fn test_sync_data_stops_at_first_missing_file() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    at.touch("file1");
+    ts.ucmd()
+        .arg("--data")
+        .arg("file1")
+        .arg("do-not-exist")
+        .fails()
+        .stderr_contains("error opening 'do-not-exist'");
+}
+
 #[test]
 fn test_sync_no_existing_files() {
     new_ucmd!()
@@ -50,6 +50,18 @@ fn test_single_default() {
         .stdout_is_fixture("prime_per_line.expected");
 }
 
+#[test]
+fn test_multiple_files() {
+    // Each file is reversed independently, in the order given on the
+    // command line; the file list itself is not reversed.
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("f1", "a\nb\nc\n");
+    at.write("f2", "d\ne\nf\n");
+    ucmd.args(&["f1", "f2"])
+        .succeeds()
+        .stdout_is("c\nb\na\nf\ne\nd\n");
+}
+
 #[test]
 fn test_single_non_newline_separator() {
     new_ucmd!()
@@ -18,6 +18,16 @@ fn test_invalid_arg() {
     new_ucmd!().arg("--definitely-invalid").fails().code_is(1);
 }
 
+#[test]
+fn test_pipe_error_conflicts_with_output_error() {
+    new_ucmd!()
+        .arg("-p")
+        .arg("--output-error=warn")
+        .fails()
+        .code_is(1)
+        .stderr_contains("cannot be used with");
+}
+
 #[test]
 fn test_tee_processing_multiple_operands() {
     // POSIX says: "Processing of at least 13 file operands shall be supported."
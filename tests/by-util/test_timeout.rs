@@ -170,3 +170,50 @@ fn test_kill_subprocess() {
         .stdout_contains("inside_trap")
         .stderr_contains("Terminated");
 }
+
+#[test]
+fn test_foreground_runs_command_normally() {
+    new_ucmd!()
+        .args(&["--foreground", "10", "echo", "-n", "abcd"])
+        .succeeds()
+        .stdout_only("abcd");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_group_signals_grandchild() {
+    // Without `--foreground`, the command runs in its own process group
+    // and the whole group is signaled on timeout, so a grandchild that the
+    // command backgrounded is killed too, not just the command itself.
+    let pidfile = tempfile::NamedTempFile::new().unwrap();
+    let pidfile_path = pidfile.path().to_str().unwrap();
+
+    new_ucmd!()
+        .args(&[
+            ".2",
+            "sh",
+            "-c",
+            &format!("sleep 30 & echo $! > {pidfile_path}; wait"),
+        ])
+        .fails()
+        .code_is(124);
+
+    // give the now-signaled grandchild a moment to actually exit
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let grandchild_pid = std::fs::read_to_string(pidfile_path)
+        .unwrap()
+        .trim()
+        .to_owned();
+    // Once reparented to init after `sh` dies, the grandchild lingers as a
+    // zombie (state 'Z') until init reaps it rather than disappearing from
+    // /proc outright, so check its state rather than mere existence.
+    let is_running = match std::fs::read_to_string(format!("/proc/{grandchild_pid}/stat")) {
+        Ok(stat) => !stat.contains(") Z "),
+        Err(_) => false,
+    };
+    assert!(
+        !is_running,
+        "grandchild process should have been killed by process-group signaling"
+    );
+}
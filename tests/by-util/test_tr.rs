@@ -1300,6 +1300,52 @@ fn check_regression_class_space() {
         .stdout_only("a123456b");
 }
 
+#[test]
+fn test_translate_multibyte_utf8_char() {
+    // A 2-byte UTF-8 character in SET1/SET2 is translated as a single
+    // symbol, instead of each of its bytes being (mis)matched on its own.
+    new_ucmd!()
+        .args(&["é", "e"])
+        .pipe_in("café")
+        .succeeds()
+        .stdout_only("cafe");
+}
+
+#[test]
+fn test_delete_multibyte_utf8_char_leaves_other_chars_using_same_bytes() {
+    // Deleting 'é' (0xC3 0xA9) must not also eat unrelated characters that
+    // happen to share one of its encoded bytes, such as '©' (0xC2 0xA9).
+    new_ucmd!()
+        .args(&["-d", "é"])
+        .pipe_in("café©")
+        .succeeds()
+        .stdout_only("caf©");
+}
+
+#[test]
+fn test_invalid_utf8_byte_passes_through_unchanged() {
+    // A byte that isn't part of any valid UTF-8 sequence must round-trip
+    // exactly, not get replaced by a multi-byte placeholder.
+    new_ucmd!()
+        .args(&["x", "y"])
+        .pipe_in(vec![b'a', 0xff, b'b'])
+        .succeeds()
+        .stdout_is_bytes(vec![b'a', 0xff, b'b']);
+}
+
+#[test]
+fn test_delete_high_byte_range_strips_multibyte_utf8() {
+    // The classic "strip 8-bit characters" idiom: every byte of a
+    // multibyte UTF-8 sequence has its high bit set, so \200-\377 must
+    // delete the whole sequence byte-for-byte, the same as GNU `tr`,
+    // even though the bytes decode to a valid codepoint.
+    new_ucmd!()
+        .args(&["-d", "\\200-\\377"])
+        .pipe_in(vec![b'c', b'a', b'f', 0xc3, 0xa9])
+        .succeeds()
+        .stdout_only("caf");
+}
+
 #[test]
 fn check_regression_class_blank() {
     // This invocation checks:
@@ -7,6 +7,8 @@
 
 use crate::common::util::TestScenario;
 use std::io::{Seek, SeekFrom, Write};
+#[cfg(unix)]
+use std::os::unix::fs::MetadataExt;
 
 static FILE1: &str = "truncate_test_1";
 static FILE2: &str = "truncate_test_2";
@@ -411,6 +413,28 @@ fn test_fifo_error_reference_file_only() {
         .stderr_contains("cannot open 'fifo' for writing: No such device or address");
 }
 
+#[cfg(unix)]
+#[test]
+fn test_io_blocks_absolute() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.make_file(FILE1);
+    ucmd.args(&["-o", "-s", "2", FILE1]).succeeds();
+    let blksize = std::fs::metadata(at.plus(FILE1)).unwrap().blksize();
+    assert_eq!(at.read_bytes(FILE1).len() as u64, 2 * blksize);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_io_blocks_relative() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let mut file = at.make_file(FILE1);
+    file.write_all(b"1234567890").unwrap();
+    let blksize = std::fs::metadata(at.plus(FILE1)).unwrap().blksize();
+    ucmd.args(&["--io-blocks", "--size=+1", FILE1]).succeeds();
+    let expected = 10 + blksize;
+    assert_eq!(at.read_bytes(FILE1).len() as u64, expected);
+}
+
 #[cfg(not(windows))]
 #[test]
 fn test_fifo_error_reference_and_size() {
@@ -32,6 +32,25 @@ fn test_sort_floating_nodes() {
         .stdout_only("a\nb\nc\nd\n");
 }
 
+#[test]
+fn test_loop_reports_participating_nodes() {
+    // Only nodes that are actually on the cycle (a, b, c) should be
+    // reported, not "d", which merely depends on a cyclic node.
+    new_ucmd!()
+        .pipe_in("a b\nb c\nc a\nc d")
+        .fails()
+        .code_is(1)
+        .stderr_contains("input contains a loop:")
+        .stderr_contains("tsort: a\n")
+        .stderr_contains("tsort: b\n")
+        .stderr_contains("tsort: c\n");
+    assert!(!new_ucmd!()
+        .pipe_in("a b\nb c\nc a\nc d")
+        .fails()
+        .stderr_str()
+        .contains("tsort: d\n"));
+}
+
 #[test]
 fn test_no_such_file() {
     new_ucmd!()
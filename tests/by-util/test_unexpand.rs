@@ -232,7 +232,7 @@ fn test_tabs_with_invalid_chars() {
 #[test]
 fn test_tabs_shortcut_with_too_large_size() {
     let arg = format!("-{}", u128::MAX);
-    let expected_error = "tab stop value is too large";
+    let expected_error = format!("tab stop is too large '{}'", u128::MAX);
 
     new_ucmd!().arg(arg).fails().stderr_contains(expected_error);
 }
@@ -260,6 +260,47 @@ fn test_multiple_files() {
         .stdout_is("contenta        b");
 }
 
+#[test]
+fn test_tabs_trailing_slash() {
+    new_ucmd!()
+        .args(&["--tabs=1,/5", "-a"])
+        //         0         1
+        //         01234567890
+        .pipe_in(" a   b    c")
+        .succeeds()
+        .stdout_is("\ta\tb\tc");
+}
+
+#[test]
+fn test_tabs_trailing_plus() {
+    new_ucmd!()
+        .args(&["--tabs=1,+5", "-a"])
+        //         0         1
+        //         012345678901
+        .pipe_in(" a    b    c")
+        .succeeds()
+        .stdout_is("\ta\tb\tc");
+}
+
+#[test]
+fn test_tabs_trailing_slash_roundtrip_with_expand() {
+    // `expand -t LIST | unexpand -t LIST` should recover the original tabs
+    // when LIST ends in a "/N" or "+N" specifier.
+    let ts = "--tabs=1,/5";
+    let expanded = TestScenario::new(util_name!())
+        .ccmd("expand")
+        .arg(ts)
+        .pipe_in("\ta\tb\tc")
+        .succeeds()
+        .stdout_move_str();
+
+    new_ucmd!()
+        .args(&[ts, "-a"])
+        .pipe_in(expanded)
+        .succeeds()
+        .stdout_is("\ta\tb\tc");
+}
+
 #[test]
 fn test_one_nonexisting_file() {
     new_ucmd!()
@@ -287,6 +287,29 @@ fn test_case2() {
     new_ucmd!().pipe_in("a\na\n").run().stdout_is("a\n");
 }
 
+#[test]
+fn test_all_repeated_with_check_chars() {
+    // -D must compare using only the first N chars (-w), same as the
+    // default dedup comparison, so lines that only differ after
+    // that point still count as repeated.
+    new_ucmd!()
+        .args(&["-D", "-w2"])
+        .pipe_in("aaX\naaY\nbbZ\ncc\n")
+        .run()
+        .stdout_is("aaX\naaY\n");
+}
+
+#[test]
+fn test_group_with_skip_fields() {
+    // --group must also respect -f (skip fields) when deciding which
+    // lines belong to the same group.
+    new_ucmd!()
+        .args(&["--group", "-f1"])
+        .pipe_in("1 a\n2 a\n1 b\n")
+        .run()
+        .stdout_is("1 a\n2 a\n\n1 b\n");
+}
+
 struct TestCase {
     name: &'static str,
     args: &'static [&'static str],
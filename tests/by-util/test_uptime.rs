@@ -30,6 +30,14 @@ fn test_uptime_since() {
     new_ucmd!().arg("--since").succeeds().stdout_matches(&re);
 }
 
+#[test]
+#[cfg(not(target_os = "openbsd"))]
+fn test_uptime_pretty() {
+    let re = Regex::new(r"^up (\d+ days?, )?(\d+ hours?, )?\d+ minutes?\n$").unwrap();
+
+    new_ucmd!().arg("--pretty").succeeds().stdout_matches(&re);
+}
+
 #[test]
 fn test_failed() {
     new_ucmd!().arg("will-fail").fails();
@@ -210,6 +210,30 @@ fn test_ascii_control() {
         .stdout_is("1\n");
 }
 
+#[test]
+fn test_count_lines_across_buffer_boundary() {
+    // Exercise the fast line-counting path (which counts newlines in
+    // fixed-size chunks) right around its internal buffer boundary, so a
+    // '\n' landing exactly at the edge of a chunk is neither double-counted
+    // nor dropped.
+    const BUF_SIZE: usize = 16 * 1024;
+    for n in [
+        BUF_SIZE - 1,
+        BUF_SIZE,
+        BUF_SIZE + 1,
+        2 * BUF_SIZE - 1,
+        2 * BUF_SIZE,
+        2 * BUF_SIZE + 1,
+    ] {
+        let data = "a\n".repeat(n);
+        new_ucmd!()
+            .args(&["-l"])
+            .pipe_in(data)
+            .succeeds()
+            .stdout_is(format!("{n}\n"));
+    }
+}
+
 #[test]
 fn test_stdin_line_len_regression() {
     new_ucmd!()
@@ -491,6 +515,16 @@ fn test_files0_from() {
         ));
 }
 
+#[test]
+fn test_files0_from_empty() {
+    // An empty --files0-from list yields zero operands: no output, success.
+    new_ucmd!()
+        .args(&["--files0-from=-"])
+        .pipe_in("")
+        .succeeds()
+        .stdout_is("");
+}
+
 #[test]
 fn test_files0_from_with_stdin() {
     new_ucmd!()